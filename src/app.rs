@@ -1,14 +1,79 @@
-use ahash::AHashSet as HashSet;
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
 use enum_dispatch::enum_dispatch;
 use finl_unicode::categories::CharacterCategories;
 //use log::info;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, VecDeque};
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    ops::RangeInclusive,
+    path::PathBuf,
+};
 use unicode_blocks as ub;
-
-use crate::*;
-
-const CAT_START: usize = 3;
+use unicode_normalization::char::canonical_combining_class;
+use unicode_properties::UnicodeEmoji;
+use unicode_script::{Script, UnicodeScript};
+
+use crate::{
+    character_set::CharacterSet,
+    copy_format::{self, CodepointPadding, CopyFormat, Separator},
+    decomposition::DecompositionNode,
+    derived_name,
+    extra_font::ExtraFont,
+    font_file::{FontFile, UnencodedGlyph},
+    name_correction,
+    raster::{self, paint_crisp_glyph, CrispGlyphCache, GlyphRasterizer, RasterFont},
+    search::{SearchEngine, SearchParams},
+    similarity::{self, SimilarityCache},
+    string_inspector, subset_font, variants, *,
+};
+
+// The font family a font opened by the user is registered as.
+const OPENED_FONT: &str = "Opened Font";
+
+const GRID_NAVIGATION_KEYS: [egui::Key; 8] = [
+    egui::Key::ArrowLeft,
+    egui::Key::ArrowRight,
+    egui::Key::ArrowUp,
+    egui::Key::ArrowDown,
+    egui::Key::PageUp,
+    egui::Key::PageDown,
+    egui::Key::Home,
+    egui::Key::End,
+];
+
+// With the command key, open the inspector section with that number.
+const INSPECTOR_SECTION_KEYS: [egui::Key; 9] = [
+    egui::Key::Num1,
+    egui::Key::Num2,
+    egui::Key::Num3,
+    egui::Key::Num4,
+    egui::Key::Num5,
+    egui::Key::Num6,
+    egui::Key::Num7,
+    egui::Key::Num8,
+    egui::Key::Num9,
+];
+
+// The default of `search_history_max_len`.
+const SEARCH_HISTORY_MAX_LEN: usize = 20;
+
+// Copies the selected glyph wherever the focus is.
+const COPY_SELECTED_CHAR: egui::KeyboardShortcut = egui::KeyboardShortcut::new(
+    egui::Modifiers::COMMAND.plus(egui::Modifiers::SHIFT),
+    egui::Key::C,
+);
+
+// What `selected_char` is while nothing is, e.g. on the first launch.
+const NO_SELECTED_CHAR: char = '\0';
+
+// The most times "Copy ×N" repeats a glyph.
+const MAX_REPEAT_COUNT: usize = 10_000;
+
+// How long a toast is shown, in seconds.
+const TOAST_DURATION: f64 = 2.0;
+
+const MIN_UI_ZOOM: f32 = 0.5;
+const MAX_UI_ZOOM: f32 = 3.0;
 
 #[enum_dispatch]
 trait CharacterInspector {
@@ -48,8 +113,11 @@ impl CharacterInspector for UnicodeMultiBlock {
 struct UnicodeCollection(HashSet<char>);
 
 impl CharacterInspector for UnicodeCollection {
+    /// The characters in codepoint order.
     fn characters(&self) -> Vec<char> {
-        self.0.iter().copied().collect()
+        let mut characters = self.0.iter().copied().collect::<Vec<_>>();
+        characters.sort_unstable();
+        characters
     }
 
     fn contains(&self, c: char) -> bool {
@@ -61,12 +129,14 @@ impl CharacterInspector for UnicodeCollection {
 struct NamedUnicodeCollections(Vec<(String, HashSet<char>)>);
 
 impl CharacterInspector for NamedUnicodeCollections {
+    /// The characters of all collections in codepoint order, each once even
+    /// if collections overlap.
     fn characters(&self) -> Vec<char> {
         self.0
             .iter()
-            .flat_map(|collection: &(String, HashSet<char>)| {
-                collection.1.iter().copied().collect::<Vec<_>>()
-            })
+            .flat_map(|collection: &(String, HashSet<char>)| collection.1.iter().copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
             .collect()
     }
 
@@ -77,6 +147,34 @@ impl CharacterInspector for NamedUnicodeCollections {
     }
 }
 
+/// All characters with a property, e.g. a general category, regardless of
+/// the block they are in.
+#[derive(Clone, Debug)]
+struct UnicodeProperty {
+    has_property: fn(char) -> bool,
+    // Property scans can pull in thousands of emoji.
+    exclude_emoji: bool,
+}
+
+impl UnicodeProperty {
+    fn new(has_property: fn(char) -> bool) -> Self {
+        Self {
+            has_property,
+            exclude_emoji: false,
+        }
+    }
+}
+
+impl CharacterInspector for UnicodeProperty {
+    fn characters(&self) -> Vec<char> {
+        ('\0'..=char::MAX).filter(|&c| self.contains(c)).collect()
+    }
+
+    fn contains(&self, c: char) -> bool {
+        (self.has_property)(c) && !(self.exclude_emoji && is_emoji(c))
+    }
+}
+
 #[derive(Clone, Debug)]
 #[enum_dispatch(CharacterInspector)]
 enum UnicodeCategory {
@@ -84,13 +182,173 @@ enum UnicodeCategory {
     MultiBlock(UnicodeMultiBlock),
     Collection(UnicodeCollection),
     NamedCollections(NamedUnicodeCollections),
+    Property(UnicodeProperty),
+}
+
+/// What the glyph grid shows. With nothing selected it shows every glyph in
+/// the font.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+enum Selection {
+    RecentlyUsed,
+    Collection,
+    ClipboardHistory,
+    Search,
+    /// An index into `GlyphanaApp::categories`.
+    Category(usize),
+}
+
+/// What hitting Enter in the search field does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+enum EnterAction {
+    ActivateSearch,
+    SelectFirstResult,
+    CopyFirstResult,
+}
+
+/// Where the glyphs in the grid come from.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+enum CharacterSource {
+    /// The fonts we ship.
+    #[default]
+    Bundled,
+    /// A face, by index, of a font file the user opened.
+    FontFace(PathBuf, usize),
+}
+
+/// A query the user ran and where they were in its results.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct SearchHistoryEntry {
+    query: String,
+    selected_char: char,
+    // The vertical scroll offset of the grid.
+    scroll_offset: f32,
+}
+
+/// A named set of glyphs the user collected.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct Collection {
+    name: String,
+    characters: HashSet<char>,
+}
+
+impl Collection {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            characters: HashSet::new(),
+        }
+    }
+}
+
+/// What happens when the view changes so the selected glyph isn't in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+enum HiddenSelection {
+    /// Keep inspecting it.
+    Keep,
+    /// Inspect the first glyph of the view instead.
+    SelectFirstShown,
+}
+
+/// How the grid marks glyphs that are in the active collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+enum CollectionMarker {
+    None,
+    /// A frame around the cell.
+    Border,
+    /// A triangle in the cell's top right corner.
+    Corner,
+}
+
+/// How much of the codepoint range of a block category the font covers.
+#[derive(Clone, Debug, Default)]
+struct BlockCoverage {
+    shown: usize,
+    unassigned: usize,
+    // Assigned codepoints the font has no glyph for.
+    missing: usize,
+}
+
+/// How many of the assigned codepoints of a block the fonts have a glyph
+/// for, for the coverage map.
+struct BlockFontCoverage {
+    block: ub::UnicodeBlock,
+    covered: usize,
+    assigned: usize,
+}
+
+/// The glyphs of the opened font no character maps to, for the window
+/// listing them.
+struct UnencodedGlyphs {
+    glyphs: Vec<UnencodedGlyph>,
+    font: RasterFont,
+    // By glyph id and size in pixels.
+    textures: HashMap<(u16, u32), egui::TextureHandle>,
+}
+
+/// What exporting subset fonts of the collection wrote, for the window
+/// reporting it.
+struct SubsetFontExport {
+    // Each file with the number of glyphs in it.
+    fonts: Vec<(PathBuf, usize)>,
+    // Glyphs of the collection none of the fonts has.
+    missing: Vec<char>,
+}
+
+/// Which characters each registered font family has glyphs for.
+#[derive(Default)]
+struct FamilyCoverage {
+    // In the order they are tried.
+    families: Vec<(egui::FontFamily, HashSet<char>)>,
+}
+
+impl FamilyCoverage {
+    /// Collects the coverage of every family egui knows, `preferred` first.
+    fn new(ctx: &egui::Context, preferred: &egui::FontFamily) -> Self {
+        ctx.fonts(|f| {
+            let mut families = f.families();
+            families.retain(|family| family != preferred);
+            families.insert(0, preferred.clone());
+
+            let mut fonts = f.lock();
+            Self {
+                families: families
+                    .into_iter()
+                    .map(|family| {
+                        let characters = fonts
+                            .fonts
+                            .font(&egui::FontId::new(10.0, family.clone())) // size is arbitrary
+                            .characters()
+                            .iter()
+                            .copied()
+                            .collect();
+                        (family, characters)
+                    })
+                    .collect(),
+            }
+        })
+    }
+
+    /// The first family with a glyph for `chr`,
+    /// [`egui::FontFamily::Proportional`] if none has one.
+    fn family_for(&self, chr: char) -> egui::FontFamily {
+        self.families
+            .iter()
+            .find(|(_, characters)| characters.contains(&chr))
+            .map_or(egui::FontFamily::Proportional, |(family, _)| family.clone())
+    }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Clone, Copy, PartialEq, Deserialize, Serialize)]
 enum GlyphScale {
     Small,
     Medium,
     Large,
+    /// A size in points set with the slider.
+    Custom(f32),
+}
+
+impl GlyphScale {
+    const SIZE_RANGE: RangeInclusive<f32> = 12.0..=96.0;
 }
 
 impl From<GlyphScale> for f32 {
@@ -99,6 +357,10 @@ impl From<GlyphScale> for f32 {
             GlyphScale::Small => 18.0,
             GlyphScale::Medium => 24.0,
             GlyphScale::Large => 36.0,
+            GlyphScale::Custom(size) => size.clamp(
+                *GlyphScale::SIZE_RANGE.start(),
+                *GlyphScale::SIZE_RANGE.end(),
+            ),
         }
     }
 }
@@ -110,60 +372,321 @@ impl From<GlyphScale> for f32 {
 pub struct GlyphanaApp {
     // The category the user selected for inspection.
     //selected_category: usize;
-    // The character the user selected for inspection.
+    // The character the user selected for inspection, `NO_SELECTED_CHAR` if
+    // none is.
     selected_char: char,
+    // Glyphs picked in the grid with Ctrl/⌘-click, e.g. to copy them at once.
+    #[serde(skip)]
+    selected_chars: BTreeSet<char>,
+    // UI icons none of the UI fonts can render. See `icon()`.
+    #[serde(skip)]
+    missing_icons: HashSet<char>,
     // The string the user entered into the search field.
     // Whether to onlky search in the subsets selected on the left panel.
     search_only_categories: bool,
     // Also search the glyph's name.
     search_name: bool,
+    // Also match loosely, see `SearchParams::fuzzy`.
+    fuzzy_search: bool,
+    // Match names against the search text as a regular expression.
+    regex_search: bool,
+    // Show search results best first rather than by codepoint.
+    rank_search_results: bool,
+    // The search results, best first. See `SearchEngine::relevance()`.
+    #[serde(skip)]
+    search_ranking: Vec<char>,
+    // Search everything but this category, an index into `categories`.
+    #[serde(skip)]
+    search_excluded_category: Option<usize>,
+    // The characters of the category they were last collected for, as
+    // collecting them can mean going through every codepoint.
+    #[serde(skip)]
+    search_excluded_chars: Option<(usize, HashSet<char>)>,
+    // How many search results fall into each block, most first.
+    #[serde(skip)]
+    search_block_counts: Vec<(ub::UnicodeBlock, usize)>,
+    // Only show the search results in this block.
+    #[serde(skip)]
+    search_block_filter: Option<ub::UnicodeBlock>,
+    // The hex codepoints typed into the range inputs above the grid.
+    codepoint_range_text: (String, String),
+    // Only show glyphs in this range; the last one both inputs were valid for.
+    codepoint_range: Option<RangeInclusive<char>>,
+    // Glyphs the user never wants to see, in any view.
+    hidden_chars: BTreeSet<char>,
+    // Narrow any view to the glyphs in the active collection.
+    #[serde(skip)]
+    only_collected: bool,
+    // Strip diacritics from the search text and what it is compared to.
+    ignore_diacritics: bool,
+    // Also find deprecated characters and those in the Tags block.
+    include_deprecated: bool,
+    // Match names regardless of word forms, e.g. "arrows" with "arrow".
+    stem_words: bool,
     // If search is case sensitive.
     case_sensitive: bool,
     recently_used: VecDeque<char>,
     recently_used_max_len: usize,
+    // Never empty.
+    collections: Vec<Collection>,
+    // An index into `collections`; what "Collect" adds to and the
+    // "Collection" entry on the left shows.
+    active_collection: usize,
+    // Narrows the collection view down by name or codepoint.
+    #[serde(skip)]
+    collection_filter: String,
+    // The single collection of versions before `collections`, moved into
+    // the first one on load.
+    #[serde(skip_serializing)]
     collection: HashSet<char>,
-    selected_category: usize,
+    // Every glyph copied this session, in the order it was first copied.
+    #[serde(skip)]
+    clipboard_history: Vec<char>,
+    record_clipboard_history: bool,
+    // What goes between glyphs when several are copied at once.
+    copy_separator: Separator,
+    // How many times "Copy ×N" repeats the selected glyph.
+    repeat_count: usize,
+    // A short message at the bottom of the window and when it appeared.
+    #[serde(skip)]
+    toast: Option<(String, f64)>,
+    // Names of the categories listed first in the side panel.
+    pinned_categories: Vec<String>,
+    // Names of the unpinned categories in the order the user moved them
+    // into; categories not in here follow in their own order.
+    custom_category_order: Vec<String>,
+    // List the other categories most selected first.
+    sort_categories_by_usage: bool,
+    // Glyph sizes picked while these categories were selected, by name.
+    // Others use `glyph_scale`.
+    category_glyph_scales: BTreeMap<String, GlyphScale>,
+    // Clicking the selected category deselects it.
+    click_to_deselect: bool,
+    // How often each category was selected this session, by name.
+    #[serde(skip)]
+    category_usage: HashMap<String, usize>,
+    // How many hex digits codepoints are shown and copied with.
+    codepoint_padding: CodepointPadding,
+    // What the grid shows. Not named `selected_category` so state persisted
+    // when that was an index doesn't fail to load.
+    selection: Option<Selection>,
     ui_search_text: String,
+    // Queries confirmed with Enter, most recent first.
+    search_history: Vec<SearchHistoryEntry>,
+    search_history_max_len: usize,
+    // The grid's vertical scroll offset in the last frame.
     #[serde(skip)]
-    search_text: String,
+    grid_scroll_offset: f32,
+    // Scroll the grid here in the next frame.
     #[serde(skip)]
-    split_search_text: Vec<String>,
+    pending_grid_scroll_offset: Option<f32>,
     #[serde(skip)]
-    split_search_text_lower: Vec<String>,
+    search_params: SearchParams,
     #[serde(skip)]
     default_font_id: egui::FontId,
     #[serde(skip)]
     font_size: f32,
     #[serde(skip)]
     categories: Vec<(String, UnicodeCategory)>,
+    // How many of the `categories`, at the end, are user character sets.
+    #[serde(skip)]
+    character_set_count: usize,
+    // Why character set files could not be loaded; shown until dismissed.
+    #[serde(skip)]
+    character_set_errors: Vec<String>,
+    // File names of the extra fonts to register, see `ExtraFont`.
+    enabled_extra_fonts: BTreeSet<String>,
+    // What `ExtraFont::directory()` has, with the data of the enabled ones.
+    #[serde(skip)]
+    extra_fonts: Vec<(ExtraFont, Option<Vec<u8>>)>,
     #[serde(skip)]
     full_glyph_cache: BTreeMap<char, String>,
+    // What `full_glyph_cache` was filled from.
+    #[serde(skip)]
+    full_glyph_cache_source: CharacterSource,
+    // The full glyph caches of sources not shown, so switching back to one
+    // doesn't rebuild it.
+    #[serde(skip)]
+    stashed_glyph_caches: HashMap<CharacterSource, BTreeMap<char, String>>,
+    // To render glyphs the glyph font doesn't have, e.g. in the clipboard
+    // history after opening a font.
+    #[serde(skip)]
+    family_coverage: FamilyCoverage,
     #[serde(skip)]
     shown_glyph_cache: BTreeMap<char, String>,
     pixels_per_point: f32,
+    // GUI zoom, relative to the display's native pixels per point.
+    ui_zoom: f32,
     glyph_scale: GlyphScale,
+    // Font size of the variants & similar-looking glyphs in the inspector.
+    related_glyph_size: f32,
+    // How many similar-looking glyphs are listed at first, and how many more
+    // each click on "+N More" adds.
+    related_glyph_limit: usize,
     stay_on_top: bool,
+    // Show the selected glyph in the window title.
+    show_glyph_in_title: bool,
+    // To only set the window title when it changes.
+    #[serde(skip)]
+    window_title: String,
+    enter_action: EnterAction,
+    hidden_selection: HiddenSelection,
+    collection_marker: CollectionMarker,
+    // Scroll the grid to the selected glyph in the next frame.
+    #[serde(skip)]
+    scroll_to_selected_char: bool,
+    // Show how many codepoints of a block category are not in the grid.
+    show_block_coverage: bool,
+    #[serde(skip)]
+    block_coverage: Option<BlockCoverage>,
+    // Leave out characters with the Emoji property in property categories.
+    exclude_emoji_from_properties: bool,
+    // Render glyphs in the grid and the preview without antialiasing.
+    crisp_glyphs: bool,
+    // List whitespace and ASCII control characters in the grid.
+    show_whitespace_and_control: bool,
+    // Show a glyph's name & codepoint when hovering it in the grid.
+    show_glyph_tooltips: bool,
+    // Add the decimal codepoint and the UTF-8 bytes to those tooltips.
+    show_tooltip_details: bool,
+    // Render emoji with the emoji icon font where it has them instead of
+    // with Noto Emoji.
+    prefer_emoji_icon_font: bool,
+    #[serde(skip)]
+    crisp_glyph_cache: CrispGlyphCache,
+    // Overlay the ink bounding box, origin and advance in the preview.
+    show_glyph_bounds: bool,
+    // Render the preview with the monospace instead of the glyph font.
+    preview_monospace: bool,
+    // Keep the inspector on the selected glyph when glyphs in the grid are
+    // clicked.
+    pin_inspector: bool,
+    #[serde(skip)]
+    monospace_rasterizer: GlyphRasterizer,
+    #[serde(skip)]
+    similarity_cache: SimilarityCache,
+    // The glyph the similar-looking glyphs were last looked up for and what
+    // was found.
+    #[serde(skip)]
+    similar_glyphs: Option<(char, Vec<char>)>,
+    // How many of `similar_glyphs` are listed.
+    #[serde(skip)]
+    similar_glyphs_shown: usize,
+    // The glyph the categories containing it were last listed for and
+    // their indices into `categories`.
+    #[serde(skip)]
+    glyph_categories: Option<(char, Vec<usize>)>,
+    // The glyph the confusables were last looked up for and what was found.
+    #[serde(skip)]
+    confusables: Option<(char, Vec<char>)>,
+    #[serde(skip)]
+    font_file: Option<FontFile>,
+    // While the window listing them is open.
+    #[serde(skip)]
+    unencoded_glyphs: Option<UnencodedGlyphs>,
+    // While the window reporting it is open.
+    #[serde(skip)]
+    subset_font_export: Option<SubsetFontExport>,
+    // Show the glyphs of an opened font in the order they are in the font.
+    font_order: bool,
+    // The opened font's glyph id of each character, to sort by for
+    // `font_order`. Filled on demand.
+    #[serde(skip)]
+    glyph_ids: HashMap<char, u16>,
+    #[serde(skip)]
+    pending_fonts: Option<egui::FontDefinitions>,
     #[serde(skip)]
     show_prefs: bool,
+    #[serde(skip)]
+    show_reset_dialog: bool,
+    // The collection the user asked to delete, until they confirm.
+    #[serde(skip)]
+    collection_to_delete: Option<usize>,
+    #[serde(skip)]
+    show_string_inspector: bool,
+    #[serde(skip)]
+    show_coverage_map: bool,
+    #[serde(skip)]
+    show_drawing_search: bool,
+    // Lines drawn to search by shape, on a canvas from 0 to 1 on both axes.
+    #[serde(skip)]
+    drawing: Vec<Vec<[f32; 2]>>,
+    // The glyphs in view that look most like `drawing`.
+    #[serde(skip)]
+    drawing_matches: Vec<char>,
+    // How many of `drawing_matches` are listed.
+    #[serde(skip)]
+    drawing_matches_shown: usize,
+    // Reads glyph names aloud; `None` if the platform can't.
+    #[cfg(feature = "speech")]
+    #[serde(skip)]
+    tts: Option<tts::Tts>,
+    // Every block; empty until the coverage map is shown.
+    #[serde(skip)]
+    coverage_map: Vec<BlockFontCoverage>,
+    // Shown in the large preview instead of the selected glyph if not empty.
+    sample_text: String,
+    // The text pasted into the string inspector.
+    #[serde(skip)]
+    inspected_string: String,
+    // List the codepoints of graphemes normalization would reorder in that
+    // order, too.
+    show_canonical_order: bool,
+    // Offer to save the app state before a reset.
+    export_before_reset: bool,
 }
 
 impl Default for GlyphanaApp {
     fn default() -> Self {
         Self {
-            selected_char: Default::default(),
+            selected_char: NO_SELECTED_CHAR,
+            selected_chars: Default::default(),
+            missing_icons: Default::default(),
             ui_search_text: Default::default(),
-            search_text: Default::default(),
-            split_search_text: Default::default(),
-            split_search_text_lower: Default::default(),
+            search_history: Vec::new(),
+            search_history_max_len: SEARCH_HISTORY_MAX_LEN,
+            grid_scroll_offset: 0.0,
+            pending_grid_scroll_offset: None,
+            search_params: Default::default(),
             search_only_categories: false,
             case_sensitive: false,
             search_name: false,
+            fuzzy_search: true,
+            regex_search: false,
+            rank_search_results: true,
+            search_ranking: Vec::new(),
+            search_excluded_category: None,
+            search_excluded_chars: None,
+            search_block_counts: Vec::new(),
+            search_block_filter: None,
+            codepoint_range_text: Default::default(),
+            codepoint_range: None,
+            hidden_chars: Default::default(),
+            only_collected: false,
+            ignore_diacritics: true,
+            include_deprecated: false,
+            stem_words: false,
             default_font_id: egui::FontId::new(24.0, egui::FontFamily::Name(NOTO_SANS.into())),
             font_size: 18.0,
             recently_used: Default::default(),
             recently_used_max_len: 1000,
+            copy_separator: Separator::None,
+            repeat_count: 10,
+            toast: None,
+            pinned_categories: Vec::new(),
+            category_glyph_scales: Default::default(),
+            custom_category_order: Vec::new(),
+            sort_categories_by_usage: false,
+            click_to_deselect: false,
+            category_usage: Default::default(),
+            codepoint_padding: CodepointPadding::Four,
+            collections: vec![Collection::new("Collection".to_string())],
+            active_collection: 0,
+            collection_filter: String::new(),
             collection: Default::default(),
-            selected_category: Default::default(),
+            clipboard_history: Default::default(),
+            record_clipboard_history: true,
+            selection: Some(Selection::RecentlyUsed),
             categories: {
                 let custom = vec![
                     (
@@ -365,6 +888,11 @@ impl Default for GlyphanaApp {
                             ub::CHESS_SYMBOLS,
                         ])),
                     ),
+                    // Last, so the stored indices of the others stay valid.
+                    (
+                        "All Symbols".to_string(),
+                        UnicodeCategory::Property(UnicodeProperty::new(|c| c.is_symbol())),
+                    ),
                 ];
                 /*
                 custom.append(
@@ -375,13 +903,68 @@ impl Default for GlyphanaApp {
                 );*/
                 custom
             },
+            character_set_count: 0,
+            character_set_errors: Vec::new(),
+            enabled_extra_fonts: Default::default(),
+            extra_fonts: Vec::new(),
             full_glyph_cache: Default::default(),
+            full_glyph_cache_source: CharacterSource::Bundled,
+            stashed_glyph_caches: Default::default(),
+            family_coverage: Default::default(),
             shown_glyph_cache: Default::default(),
 
             pixels_per_point: Default::default(),
+            ui_zoom: 1.0,
             glyph_scale: GlyphScale::Medium,
+            related_glyph_size: 24.0,
+            related_glyph_limit: 24,
             stay_on_top: false,
+            show_glyph_in_title: false,
+            window_title: String::new(),
+            enter_action: EnterAction::ActivateSearch,
+            hidden_selection: HiddenSelection::Keep,
+            collection_marker: CollectionMarker::Corner,
+            scroll_to_selected_char: false,
+            show_block_coverage: true,
+            block_coverage: None,
+            exclude_emoji_from_properties: false,
+            crisp_glyphs: false,
+            show_whitespace_and_control: false,
+            show_glyph_tooltips: true,
+            show_tooltip_details: false,
+            prefer_emoji_icon_font: false,
+            crisp_glyph_cache: Default::default(),
+            show_glyph_bounds: false,
+            preview_monospace: false,
+            pin_inspector: false,
+            monospace_rasterizer: Default::default(),
+            similarity_cache: Default::default(),
+            similar_glyphs: None,
+            similar_glyphs_shown: 0,
+            glyph_categories: None,
+            confusables: None,
+            font_file: None,
+            unencoded_glyphs: None,
+            subset_font_export: None,
+            font_order: false,
+            glyph_ids: Default::default(),
+            pending_fonts: None,
             show_prefs: false,
+            show_reset_dialog: false,
+            collection_to_delete: None,
+            show_string_inspector: false,
+            show_coverage_map: false,
+            show_drawing_search: false,
+            drawing: Vec::new(),
+            drawing_matches: Vec::new(),
+            drawing_matches_shown: 0,
+            #[cfg(feature = "speech")]
+            tts: None,
+            coverage_map: Vec::new(),
+            inspected_string: String::new(),
+            show_canonical_order: true,
+            sample_text: String::new(),
+            export_before_reset: true,
         }
     }
 }
@@ -392,26 +975,77 @@ impl GlyphanaApp {
         // This is also where you can customize the look and feel of egui using
         // `cc.egui_ctx.set_visuals` and `cc.egui_ctx.set_fonts`.
 
-        // Add the Noto fonts -- what we use to cover as much unicode as possible for now.
-        cc.egui_ctx.set_fonts(Self::fonts());
-
         // Load previous app state (if any).
-        let mut glyphana = if let Some(storage) = cc.storage {
+        let mut glyphana: Self = if let Some(storage) = cc.storage {
             eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default()
         } else {
             Self::default()
         };
 
+        if glyphana.collections.is_empty() {
+            glyphana.collections = Self::default().collections;
+        }
+        let collection = std::mem::take(&mut glyphana.collection);
+        glyphana.collections[0].characters.extend(collection);
+        glyphana.active_collection = glyphana
+            .active_collection
+            .min(glyphana.collections.len() - 1);
+
+        // Add the Noto fonts -- what we use to cover as much unicode as possible for now.
+        glyphana.scan_extra_fonts();
+        let fonts = glyphana.font_definitions();
+        cc.egui_ctx.set_fonts(fonts.clone());
+
         glyphana.pixels_per_point = cc.egui_ctx.pixels_per_point();
 
         glyphana.default_font_id = egui::FontId::new(
-            glyphana.glyph_scale.into(),
+            glyphana.glyph_scale().into(),
             egui::FontFamily::Name(NOTO_SANS.into()),
         );
 
+        glyphana.update_property_categories();
+        glyphana.load_character_sets();
+
+        #[cfg(feature = "speech")]
+        {
+            glyphana.tts = tts::Tts::default()
+                .map_err(|error| log::warn!("Text to speech is unavailable: {error}"))
+                .ok();
+        }
+
+        glyphana.crisp_glyph_cache = CrispGlyphCache::new(GlyphRasterizer::new(
+            &fonts,
+            &egui::FontFamily::Name(NOTO_SANS.into()),
+        ));
+        glyphana.monospace_rasterizer = GlyphRasterizer::new(&fonts, &egui::FontFamily::Monospace);
+
+        // Buttons are labeled with the proportional family.
+        let ui_rasterizer = GlyphRasterizer::new(&fonts, &egui::FontFamily::Proportional);
+        glyphana.missing_icons = UI_ICONS
+            .iter()
+            .filter(|(icon, _)| ui_rasterizer.font_for_char(*icon).is_none())
+            .map(|&(icon, fallback)| {
+                log::warn!(
+                    "No UI font has a glyph for icon U+{:04X}, using “{fallback}” instead",
+                    icon as u32
+                );
+                icon
+            })
+            .collect();
+
         glyphana
     }
 
+    /// The label for a UI icon: the icon itself or, if no UI font has a
+    /// glyph for it, its text fallback from [`UI_ICONS`].
+    fn icon(&self, icon: char) -> String {
+        UI_ICONS
+            .iter()
+            .filter(|_| self.missing_icons.contains(&icon))
+            .find(|(ui_icon, _)| icon == *ui_icon)
+            .map_or_else(|| icon.to_string(), |(_, fallback)| fallback.to_string())
+    }
+
     fn _available_characters(
         &self,
         ui: &egui::Ui,
@@ -432,7 +1066,9 @@ impl GlyphanaApp {
         })
     }
 
-    fn fonts() -> egui::FontDefinitions {
+    /// Our fonts. With `prefer_emoji_icon_font` the glyph font falls back to
+    /// the emoji icon font before Noto Emoji.
+    fn fonts(prefer_emoji_icon_font: bool) -> egui::FontDefinitions {
         let mut fonts = egui::FontDefinitions::default();
 
         //let mut font_data: BTreeMap<String, egui::FontData> = BTreeMap::new();
@@ -497,13 +1133,19 @@ impl GlyphanaApp {
             }),
         );
 
+        let emoji_fonts = if prefer_emoji_icon_font {
+            [EMOJI_ICON.to_owned(), NOTO_EMOJI.to_owned()]
+        } else {
+            [NOTO_EMOJI.to_owned(), EMOJI_ICON.to_owned()]
+        };
+
         fonts.families.insert(
             egui::FontFamily::Name(NOTO_SANS.into()),
             vec![
                 NOTO_SANS.to_owned(),
                 NOTO_SANS_MATH.to_owned(),
-                NOTO_EMOJI.to_owned(),
-                EMOJI_ICON.to_owned(),
+                emoji_fonts[0].clone(),
+                emoji_fonts[1].clone(),
                 NOTO_SYMBOLS.to_owned(),
                 NOTO_SYMBOLS2.to_owned(),
                 //NOTO_SIGN_WRITING.to_owned(),
@@ -511,10 +1153,353 @@ impl GlyphanaApp {
             ],
         );
 
+        // Each font also gets a family of its own so we can render a glyph
+        // with a particular font.
+        for name in fonts.font_data.keys() {
+            fonts
+                .families
+                .entry(egui::FontFamily::Name(name.as_str().into()))
+                .or_insert_with(|| vec![name.clone()]);
+        }
+
         fonts
     }
 
-    fn settings_ui(&mut self, _ui: &mut egui::Ui) {}
+    fn settings_ui(&mut self, ui: &mut egui::Ui) {
+        if ui
+            .checkbox(&mut self.ignore_diacritics, "Ignore Diacritics in Search")
+            .on_hover_ui(|ui| {
+                ui.label("Match e.g. “cafe” with “café” and vice versa, unless Match Case is on");
+            })
+            .changed()
+        {
+            self.update_search_text_and_shown_glyph_cache();
+        }
+
+        if ui
+            .checkbox(
+                &mut self.include_deprecated,
+                "Include Deprecated Characters in Search",
+            )
+            .on_hover_ui(|ui| {
+                ui.label("Also find deprecated characters and those in the Tags block");
+            })
+            .changed()
+        {
+            self.update_search_text_and_shown_glyph_cache();
+        }
+
+        if ui
+            .checkbox(&mut self.stem_words, "Match Word Forms in Name Search")
+            .on_hover_ui(|ui| {
+                ui.label("Match e.g. “arrows” with “arrow” and “pointing” with “points”");
+            })
+            .changed()
+        {
+            self.update_search_text_and_shown_glyph_cache();
+        }
+
+        if ui
+            .checkbox(
+                &mut self.rank_search_results,
+                "Sort Search Results by Relevance",
+            )
+            .on_hover_ui(|ui| {
+                ui.label(
+                    "Exact name matches first, then names with words starting with the search \
+                     terms, then names containing them",
+                );
+            })
+            .changed()
+        {
+            self.update_search_text_and_shown_glyph_cache();
+        }
+
+        ui.horizontal(|ui| {
+            if ui
+                .add(egui::DragValue::new(&mut self.search_history_max_len).clamp_range(1..=100))
+                .changed()
+            {
+                self.search_history.truncate(self.search_history_max_len);
+            }
+            ui.label("Searches Remembered");
+        })
+        .response
+        .on_hover_ui(|ui| {
+            ui.label("How Many Searches Confirmed with Enter the Search History Keeps");
+        });
+
+        ui.separator();
+
+        ui.label("Enter in Search Field");
+        ui.radio_value(
+            &mut self.enter_action,
+            EnterAction::ActivateSearch,
+            "Activate Search",
+        );
+        ui.radio_value(
+            &mut self.enter_action,
+            EnterAction::SelectFirstResult,
+            "Select First Result",
+        );
+        ui.radio_value(
+            &mut self.enter_action,
+            EnterAction::CopyFirstResult,
+            "Copy First Result",
+        );
+
+        ui.separator();
+
+        ui.label("When the Selected Glyph Leaves the View");
+        ui.radio_value(
+            &mut self.hidden_selection,
+            HiddenSelection::Keep,
+            "Keep Inspecting It",
+        );
+        ui.radio_value(
+            &mut self.hidden_selection,
+            HiddenSelection::SelectFirstShown,
+            "Select the First Shown Glyph",
+        );
+
+        ui.separator();
+
+        ui.label("Mark Glyphs in the Active Collection With")
+            .on_hover_ui(|ui| {
+                ui.label("Drawn in the Warning Color to Stand Out From the Selection");
+            });
+        ui.horizontal(|ui| {
+            ui.radio_value(
+                &mut self.collection_marker,
+                CollectionMarker::None,
+                "Nothing",
+            );
+            ui.radio_value(
+                &mut self.collection_marker,
+                CollectionMarker::Border,
+                "Border",
+            );
+            ui.radio_value(
+                &mut self.collection_marker,
+                CollectionMarker::Corner,
+                "Corner",
+            );
+        });
+
+        ui.separator();
+
+        ui.checkbox(
+            &mut self.record_clipboard_history,
+            "Record Copied Glyphs in Clipboard History",
+        );
+
+        ui.label("Codepoint Digits");
+        ui.horizontal(|ui| {
+            for padding in CodepointPadding::ALL {
+                ui.radio_value(&mut self.codepoint_padding, padding, padding.label());
+            }
+        })
+        .response
+        .on_hover_ui(|ui| {
+            ui.label("How Many Hex Digits to Pad Codepoints to in the Inspector & when Copying");
+        });
+
+        ui.label("Separator when Copying Several Glyphs");
+        ui.horizontal(|ui| {
+            for separator in Separator::ALL {
+                ui.radio_value(&mut self.copy_separator, separator, separator.label());
+            }
+        });
+
+        ui.checkbox(&mut self.show_block_coverage, "Show Block Coverage")
+            .on_hover_ui(|ui| {
+                ui.label("Count Unassigned Codepoints & Glyphs Missing from the Font");
+            });
+
+        if ui
+            .checkbox(
+                &mut self.exclude_emoji_from_properties,
+                "Exclude Emoji from Property Categories",
+            )
+            .on_hover_ui(|ui| {
+                ui.label("Keep Emoji out of Categories like All Symbols");
+            })
+            .changed()
+        {
+            self.update_property_categories();
+            self.update_search_text_and_shown_glyph_cache();
+        }
+
+        ui.checkbox(
+            &mut self.click_to_deselect,
+            "Click Active Category to Deselect",
+        )
+        .on_hover_ui(|ui| {
+            ui.label("Show All Glyphs again when Clicking the Selected Category");
+        });
+
+        ui.checkbox(
+            &mut self.sort_categories_by_usage,
+            "Sort Categories by Usage",
+        )
+        .on_hover_ui(|ui| {
+            ui.label("List the Categories Selected Most this Session First, below Pinned Ones");
+        });
+
+        if ui
+            .add_enabled(
+                !self.custom_category_order.is_empty(),
+                egui::Button::new("Reset Category Order"),
+            )
+            .on_hover_ui(|ui| {
+                ui.label("Undo Moving Categories Up & Down");
+            })
+            .clicked()
+        {
+            self.custom_category_order.clear();
+        }
+
+        ui.checkbox(
+            &mut self.show_glyph_in_title,
+            "Show Selected Glyph in Window Title",
+        )
+        .on_hover_ui(|ui| {
+            ui.label("Keep the Glyph & its Codepoint in View in the Title Bar & Taskbar");
+        });
+
+        ui.checkbox(&mut self.show_glyph_tooltips, "Show Hover Tooltips")
+            .on_hover_ui(|ui| {
+                ui.label("Show Name & Codepoint when Hovering a Glyph in the Grid");
+            });
+
+        ui.indent("tooltip_details", |ui| {
+            ui.add_enabled(
+                self.show_glyph_tooltips,
+                egui::Checkbox::new(&mut self.show_tooltip_details, "Show Details"),
+            )
+            .on_hover_ui(|ui| {
+                ui.label("Add the Decimal Codepoint & UTF-8 to the Tooltips");
+            });
+        });
+
+        if ui
+            .checkbox(&mut self.prefer_emoji_icon_font, "Prefer Emoji Icon Font")
+            .on_hover_ui(|ui| {
+                ui.label("Render Emoji with the Icon Font before Noto Emoji");
+            })
+            .changed()
+        {
+            self.set_fonts(ui.ctx());
+        }
+
+        ui.add(
+            egui::Slider::new(&mut self.related_glyph_size, 12.0..=48.0)
+                .text("Related Glyph Size")
+                .suffix("pt"),
+        )
+        .on_hover_ui(|ui| {
+            ui.label("Size of Variants & Similar-Looking Glyphs in the Inspector");
+        });
+
+        ui.horizontal(|ui| {
+            ui.add(egui::DragValue::new(&mut self.related_glyph_limit).clamp_range(1..=256));
+            ui.label("Similar-Looking Glyphs Listed");
+        })
+        .response
+        .on_hover_ui(|ui| {
+            ui.label("How Many are Listed at First & Added with each Click on \"+N More\"");
+        });
+
+        ui.checkbox(&mut self.crisp_glyphs, "Crisp Glyphs (No Antialiasing)")
+            .on_hover_ui(|ui| {
+                ui.label("Render Grid & Preview Glyphs Pixel-Sharp (for Pixel Fonts)");
+            });
+
+        if ui
+            .checkbox(
+                &mut self.show_whitespace_and_control,
+                "Show Whitespace & Control Characters",
+            )
+            .on_hover_ui(|ui| {
+                ui.label("List Spaces, Tabs, Control Codes & Co. in the Grid");
+            })
+            .changed()
+        {
+            // Refilled next frame.
+            self.full_glyph_cache.clear();
+            self.stashed_glyph_caches.clear();
+        }
+
+        ui.separator();
+
+        ui.label("Extra Fonts").on_hover_ui(|ui| {
+            ui.label("Fonts like Noto Sans Devanagari to Cover Scripts the Bundled Fonts Lack");
+        });
+
+        if self.extra_fonts.is_empty() {
+            if let Some(directory) = ExtraFont::directory() {
+                ui.label(format!(
+                    "Put .ttf or .otf files into {} to use them.",
+                    directory.display()
+                ));
+            }
+        }
+
+        let mut toggled = None;
+        for (index, (extra_font, data)) in self.extra_fonts.iter().enumerate() {
+            let mut enabled = data.is_some();
+            if ui.checkbox(&mut enabled, &extra_font.name).changed() {
+                toggled = Some((index, enabled));
+            }
+        }
+        if let Some((index, enabled)) = toggled {
+            self.set_extra_font_enabled(ui.ctx(), index, enabled);
+        }
+
+        if ui.button("Rescan Fonts Folder").clicked() {
+            self.scan_extra_fonts();
+            self.set_extra_fonts(ui.ctx());
+        }
+
+        ui.separator();
+
+        egui::CollapsingHeader::new(format!("Hidden Characters ({})", self.hidden_chars.len()))
+            .show(ui, |ui| {
+                if self.hidden_chars.is_empty() {
+                    ui.label("Right-click a glyph in the grid to hide it.");
+                    return;
+                }
+
+                let mut unhide = Vec::new();
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for &chr in &self.hidden_chars {
+                            ui.horizontal(|ui| {
+                                if ui.button("Show").clicked() {
+                                    unhide.push(chr);
+                                }
+                                ui.label(format!(
+                                    "{chr} {} {}",
+                                    self.codepoint_padding.format(chr),
+                                    title_case(&self.display_name(chr))
+                                ));
+                            });
+                        }
+                    });
+
+                if ui.button("Show All").clicked() {
+                    unhide.extend(self.hidden_chars.iter().copied());
+                }
+
+                if !unhide.is_empty() {
+                    for chr in unhide {
+                        self.hidden_chars.remove(&chr);
+                    }
+                    self.update_search_text_and_shown_glyph_cache();
+                }
+            });
+    }
 }
 
 impl eframe::App for GlyphanaApp {
@@ -527,12 +1512,70 @@ impl eframe::App for GlyphanaApp {
     fn update(&mut self, ctx: &eframe::egui::Context, frame: &mut eframe::Frame) {
         //println!("{:?}", self.categories[1].1);
 
+        // Fonts passed to `set_fonts()` only become available in the next frame.
+        if let Some(fonts) = self.pending_fonts.take() {
+            let family = self.glyph_font_family();
+
+            self.crisp_glyph_cache = CrispGlyphCache::new(GlyphRasterizer::new(&fonts, &family));
+            self.monospace_rasterizer = GlyphRasterizer::new(&fonts, &egui::FontFamily::Monospace);
+            self.similarity_cache = Default::default();
+            self.similar_glyphs = None;
+            self.glyph_ids.clear();
+            self.default_font_id.family = family;
+
+            // Refilled below, from the stash if we had this source before.
+            if !self.full_glyph_cache.is_empty() {
+                self.stashed_glyph_caches.insert(
+                    self.full_glyph_cache_source.clone(),
+                    std::mem::take(&mut self.full_glyph_cache),
+                );
+            }
+        }
+
+        self.default_font_id.family = resolve_font_family(ctx, &self.default_font_id.family);
+        // The selected category may have its own.
+        self.default_font_id.size = self.glyph_scale().into();
+
         // Fill character caches on first run.
         if self.full_glyph_cache.is_empty() {
-            self.full_glyph_cache = available_characters(ctx, self.default_font_id.family.clone());
+            let source = self.character_source();
+
+            self.full_glyph_cache = match self.stashed_glyph_caches.remove(&source) {
+                Some(cache) => cache,
+                None => {
+                    let stashed_glyph_caches = &self.stashed_glyph_caches;
+
+                    available_characters(
+                        ctx,
+                        self.default_font_id.family.clone(),
+                        self.show_whitespace_and_control,
+                        // Names are the same whatever the source.
+                        |chr| {
+                            stashed_glyph_caches
+                                .values()
+                                .find_map(|cache| cache.get(&chr).cloned())
+                                .unwrap_or_else(|| char_name(chr))
+                        },
+                    )
+                }
+            };
+            self.full_glyph_cache_source = source;
+            self.family_coverage = FamilyCoverage::new(ctx, &self.default_font_id.family);
+            self.coverage_map.clear();
+            self.confusables = None;
             self.update_search_text_and_shown_glyph_cache();
         }
 
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.back_out(ctx);
+        }
+
+        if ctx.input_mut(|i| i.consume_shortcut(&COPY_SELECTED_CHAR)) {
+            self.copy_selected_char(ctx);
+        }
+
+        self.render_toast(ctx);
+
         let mut show_prefs = self.show_prefs;
 
         egui::Window::new("⚙ Preferences")
@@ -544,31 +1587,167 @@ impl eframe::App for GlyphanaApp {
 
         self.show_prefs = show_prefs;
 
-        //
+        let mut show_string_inspector = self.show_string_inspector;
 
-        /*if let Ok(event) = tray_icon::TrayEvent::receiver().try_recv() {
-            info!("tray event: {event:?}");
-        }
+        egui::Window::new("🔬 String Inspector")
+            .open(&mut show_string_inspector)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                self.render_string_inspector(ui);
+            });
 
-        use tray_icon::menu::MenuEvent;
-        if let Ok(event) = MenuEvent::receiver().try_recv() {
-            match event.id {
-                2 => { info!("Baaaaar"); frame.set_always_on_top(true) },
-                4 => frame.close(),
-                _ => unreachable!(),
-            };
-        }*/
+        self.show_string_inspector = show_string_inspector;
 
-        // Update global app state.
-        frame.set_always_on_top(self.stay_on_top);
+        let mut show_coverage_map = self.show_coverage_map;
+
+        egui::Window::new("Coverage Map")
+            .open(&mut show_coverage_map)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                self.render_coverage_map(ui);
+            });
+
+        self.show_coverage_map = show_coverage_map;
+
+        let mut show_drawing_search = self.show_drawing_search;
+
+        egui::Window::new("✏ Draw to Search")
+            .open(&mut show_drawing_search)
+            .resizable(false)
+            .show(ctx, |ui| {
+                self.render_drawing_search(ui);
+            });
+
+        self.show_drawing_search = show_drawing_search;
+
+        let mut show_unencoded_glyphs = self.unencoded_glyphs.is_some();
+
+        egui::Window::new("Unencoded Glyphs")
+            .open(&mut show_unencoded_glyphs)
+            .show(ctx, |ui| {
+                self.render_unencoded_glyphs(ui);
+            });
+
+        if !show_unencoded_glyphs {
+            self.unencoded_glyphs = None;
+        }
+
+        let mut show_subset_font_export = self.subset_font_export.is_some();
+
+        egui::Window::new("Export Subset Fonts")
+            .open(&mut show_subset_font_export)
+            .show(ctx, |ui| {
+                self.render_subset_font_export(ui);
+            });
+
+        if !show_subset_font_export {
+            self.subset_font_export = None;
+        }
+
+        if self.show_reset_dialog {
+            egui::Window::new("⟲ Factory Reset")
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+                .show(ctx, |ui| {
+                    ui.label("This resets all settings and clears the collections & histories.");
+
+                    ui.checkbox(&mut self.export_before_reset, "Export Before Reset");
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Reset").clicked()
+                            && (!self.export_before_reset || self.export_app_state())
+                        {
+                            self.reset(ctx);
+                        }
+
+                        if ui.button("Cancel").clicked() {
+                            self.show_reset_dialog = false;
+                        }
+                    });
+                });
+        }
+
+        if let Some(index) = self.collection_to_delete {
+            egui::Window::new("🗑 Delete Collection")
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+                .show(ctx, |ui| {
+                    if let Some(collection) = self.collections.get(index) {
+                        ui.label(format!(
+                            "This deletes \"{}\" and the {} glyphs in it.",
+                            collection.name,
+                            collection.characters.len()
+                        ));
+                    }
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Delete").clicked() {
+                            self.delete_collection(index);
+                        }
+
+                        if ui.button("Cancel").clicked() {
+                            self.collection_to_delete = None;
+                        }
+                    });
+                });
+        }
+
+        if !self.character_set_errors.is_empty() {
+            egui::Window::new("Character Sets")
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+                .show(ctx, |ui| {
+                    ui.label("These character sets could not be loaded:");
+
+                    for error in &self.character_set_errors {
+                        ui.label(error);
+                    }
+
+                    if ui.button("OK").clicked() {
+                        self.character_set_errors.clear();
+                    }
+                });
+        }
+
+        //
+
+        /*if let Ok(event) = tray_icon::TrayEvent::receiver().try_recv() {
+            info!("tray event: {event:?}");
+        }
+
+        use tray_icon::menu::MenuEvent;
+        if let Ok(event) = MenuEvent::receiver().try_recv() {
+            match event.id {
+                2 => { info!("Baaaaar"); frame.set_always_on_top(true) },
+                4 => frame.close(),
+                _ => unreachable!(),
+            };
+        }*/
+
+        // Update global app state.
+        frame.set_always_on_top(self.stay_on_top);
+
+        let title = self.window_title(
+            (self.show_glyph_in_title && self.has_selected_char()).then_some(self.selected_char),
+        );
+        if title != self.window_title {
+            frame.set_window_title(&title);
+            self.window_title = title;
+        }
+
+        self.update_ui_zoom(ctx, frame.info().native_pixels_per_point);
 
         egui::TopBottomPanel::top("top_bar").show(ctx, |ui| {
             // Hamburger menu.
             egui::menu::bar(ui, |ui| {
-                ui.menu_button(format!("{}", super::HAMBURGER), |ui| {
+                ui.menu_button(self.icon(super::HAMBURGER), |ui| {
                     #[cfg(debug_assertions)]
-                    if ui.button("⟲ Factory Reset App State").clicked() {
-                        *self = Self::default();
+                    if ui.button("⟲ Factory Reset App State…").clicked() {
+                        ui.close_menu();
+                        self.show_reset_dialog = true;
                     }
 
                     #[cfg(debug_assertions)]
@@ -582,13 +1761,89 @@ impl eframe::App for GlyphanaApp {
 
                     ui.add_enabled_ui(false, |ui| ui.button("🗛 Glyph Size"));
 
+                    let mut glyph_scale = self.glyph_scale();
+
                     ui.vertical(|ui| {
-                        ui.radio_value(&mut self.glyph_scale, GlyphScale::Small, "Small");
-                        ui.radio_value(&mut self.glyph_scale, GlyphScale::Medium, "Medium");
-                        ui.radio_value(&mut self.glyph_scale, GlyphScale::Large, "Large");
+                        ui.radio_value(&mut glyph_scale, GlyphScale::Small, "Small");
+                        ui.radio_value(&mut glyph_scale, GlyphScale::Medium, "Medium");
+                        ui.radio_value(&mut glyph_scale, GlyphScale::Large, "Large");
+
+                        // Cell, glyph and the labels in cells all follow this size.
+                        let mut size = f32::from(glyph_scale);
+                        if ui
+                            .add(
+                                egui::Slider::new(&mut size, GlyphScale::SIZE_RANGE)
+                                    .suffix("pt")
+                                    .integer(),
+                            )
+                            .on_hover_ui(|ui| {
+                                ui.label("Any Size in Between or Beyond the Steps Above");
+                            })
+                            .changed()
+                        {
+                            glyph_scale = GlyphScale::Custom(size);
+                        }
                     });
 
-                    self.default_font_id.size = self.glyph_scale.into();
+                    if glyph_scale != self.glyph_scale() {
+                        self.set_glyph_scale(glyph_scale);
+                    }
+
+                    ui.separator();
+
+                    ui.add_enabled_ui(false, |ui| ui.button("🔍 Zoom"));
+
+                    if ui
+                        .add_enabled(
+                            self.ui_zoom < MAX_UI_ZOOM,
+                            egui::Button::new("Zoom In").shortcut_text(
+                                ui.ctx()
+                                    .format_shortcut(&egui::gui_zoom::kb_shortcuts::ZOOM_IN),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        self.ui_zoom += 0.1;
+                    }
+
+                    if ui
+                        .add_enabled(
+                            MIN_UI_ZOOM < self.ui_zoom,
+                            egui::Button::new("Zoom Out").shortcut_text(
+                                ui.ctx()
+                                    .format_shortcut(&egui::gui_zoom::kb_shortcuts::ZOOM_OUT),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        self.ui_zoom -= 0.1;
+                    }
+
+                    if ui
+                        .add_enabled(
+                            1.0 != self.ui_zoom,
+                            egui::Button::new("Reset Zoom").shortcut_text(
+                                ui.ctx()
+                                    .format_shortcut(&egui::gui_zoom::kb_shortcuts::ZOOM_RESET),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        self.ui_zoom = 1.0;
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            egui::Button::new("📋 Copy Selected Glyph")
+                                .shortcut_text(ui.ctx().format_shortcut(&COPY_SELECTED_CHAR)),
+                        )
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.copy_selected_char(ui.ctx());
+                    }
 
                     ui.separator();
 
@@ -596,10 +1851,108 @@ impl eframe::App for GlyphanaApp {
                         self.recently_used.clear();
                     }
 
+                    if ui.button("🗑 Clear Clipboard History").clicked() {
+                        self.clipboard_history.clear();
+                        self.update_search_text_and_shown_glyph_cache();
+                    }
+
                     ui.separator();
 
+                    if ui
+                        .add_enabled(
+                            !self.clipboard_history.is_empty(),
+                            egui::Button::new("📋 Export Clipboard History…"),
+                        )
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.export_clipboard_history();
+                    }
+
                     ui.add_enabled_ui(false, |ui| ui.button("Export Collection…"));
 
+                    if ui
+                        .add_enabled(
+                            !self.active_collection().characters.is_empty(),
+                            egui::Button::new("Export Subset Fonts…"),
+                        )
+                        .on_hover_ui(|ui| {
+                            ui.label("Save Fonts Containing Only the Glyphs of the Collection");
+                        })
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.export_subset_fonts();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .button("⟳ Reload Character Sets")
+                        .on_hover_ui(|ui| {
+                            ui.label(format!(
+                                "Load Categories from the JSON Files in {}",
+                                CharacterSet::directory()
+                                    .map(|directory| directory.display().to_string())
+                                    .unwrap_or_default()
+                            ));
+                        })
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.load_character_sets();
+                        self.update_search_text_and_shown_glyph_cache();
+                    }
+
+                    ui.separator();
+
+                    if ui.button("🗁 Open Font…").clicked() {
+                        ui.close_menu();
+                        self.open_font(ctx);
+                    }
+
+                    if ui
+                        .add_enabled(self.font_file.is_some(), egui::Button::new("Close Font"))
+                        .clicked()
+                    {
+                        self.font_file = None;
+                        self.set_fonts(ctx);
+                    }
+
+                    if ui
+                        .add_enabled(
+                            self.font_file.is_some(),
+                            egui::Button::new("Unencoded Glyphs…"),
+                        )
+                        .on_hover_text("Glyphs of the Font No Character Maps To")
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.open_unencoded_glyphs();
+                    }
+
+                    ui.separator();
+
+                    if ui.button("🔬 String Inspector…").clicked() {
+                        self.show_string_inspector = true;
+                    }
+
+                    if ui
+                        .button("Coverage Map…")
+                        .on_hover_text("How Much of Each Block the Fonts Cover")
+                        .clicked()
+                    {
+                        self.show_coverage_map = true;
+                    }
+
+                    if ui
+                        .button("✏ Draw to Search…")
+                        .on_hover_text("Find Glyphs in View by Drawing Them")
+                        .clicked()
+                    {
+                        self.show_drawing_search = true;
+                    }
+
                     if ui.button("⚙ Preferences…").clicked() {
                         self.show_prefs = true;
                     }
@@ -611,54 +1964,152 @@ impl eframe::App for GlyphanaApp {
                     }
                 });
 
-                ui.toggle_value(&mut self.stay_on_top, format!("{}", super::PUSH_PIN))
+                let push_pin = self.icon(super::PUSH_PIN);
+                ui.toggle_value(&mut self.stay_on_top, push_pin)
                     .on_hover_ui(|ui| {
                         ui.label("Pin Glyphana Window to Top");
                     });
 
+                if let Some(font_file) = &self.font_file {
+                    let mut face = font_file.face;
+
+                    if 1 < font_file.faces.len() {
+                        egui::ComboBox::from_id_source("font_face")
+                            .selected_text(font_file.current_face().to_string())
+                            .show_ui(ui, |ui| {
+                                for (index, font_face) in font_file.faces.iter().enumerate() {
+                                    ui.selectable_value(&mut face, index, font_face.to_string());
+                                }
+                            })
+                            .response
+                            .on_hover_text(font_file.file_name());
+                    } else {
+                        ui.label(font_file.current_face().to_string())
+                            .on_hover_text(font_file.file_name());
+                    }
+
+                    if face != font_file.face {
+                        self.set_font_face(ctx, face);
+                    }
+
+                    ui.toggle_value(&mut self.font_order, "Font Order")
+                        .on_hover_ui(|ui| {
+                            ui.label("Sort Glyphs by Their ID in the Font Instead of Codepoint");
+                        });
+                }
+
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if ui.button(format!("{}", super::CANCELLATION)).clicked() {
+                    if ui.button(self.icon(super::CANCELLATION)).clicked() {
                         self.ui_search_text.clear();
                         self.update_search_text_and_shown_glyph_cache();
                     }
 
-                    if ui
-                        .add(
-                            egui::TextEdit::singleline(&mut self.ui_search_text)
-                                //.desired_width(120.0)
-                                .hint_text("🔍 Search"),
-                        )
-                        .changed()
-                    {
-                        self.selected_category = 2;
+                    let search_field = ui.add(
+                        egui::TextEdit::singleline(&mut self.ui_search_text)
+                            //.desired_width(120.0)
+                            .hint_text("🔍 Search"),
+                    );
+
+                    if search_field.changed() {
+                        self.selection = Some(Selection::Search);
                         self.update_search_text_and_shown_glyph_cache();
                     }
-                    //self.search_text = decancer::cure(&self.ui_search_text).into_str();
 
-                    if !self.case_sensitive {
-                        self.search_text = self.search_text.to_lowercase();
+                    self.search_history_popup(ui, &search_field);
+
+                    if search_field.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        ui.memory_mut(|memory| memory.close_popup());
+                        self.selection = Some(Selection::Search);
+                        self.update_search_text_and_shown_glyph_cache();
+                        self.remember_search();
+
+                        if let Some(chr) = self.first_shown_char() {
+                            match self.enter_action {
+                                EnterAction::ActivateSearch => (),
+                                EnterAction::SelectFirstResult => self.selected_char = chr,
+                                EnterAction::CopyFirstResult => {
+                                    self.selected_char = chr;
+                                    self.copy_to_clipboard(ctx, [chr], chr.to_string());
+                                }
+                            }
+                        }
                     }
+                    //self.search_text = decancer::cure(&self.ui_search_text).into_str();
 
-                    ui.toggle_value(
-                        &mut self.case_sensitive,
-                        format!("{}", super::LOWER_UPPER_CASE),
-                    )
-                    .on_hover_ui(|ui| {
-                        self.selected_category = 2;
-                        ui.label("Match Case");
+                    let clock = self.icon(super::CLOCK);
+                    ui.add_enabled_ui(!self.search_history.is_empty(), |ui| {
+                        ui.menu_button(clock, |ui| {
+                            for index in 0..self.search_history.len() {
+                                if ui.button(&self.search_history[index].query).clicked() {
+                                    ui.close_menu();
+                                    self.rerun_search(index);
+                                }
+                            }
+
+                            ui.separator();
+
+                            if ui.button("🗑 Clear Search History").clicked() {
+                                ui.close_menu();
+                                self.search_history.clear();
+                            }
+                        })
+                        .response
+                        .on_hover_text("Recent Searches");
                     });
 
+                    let lower_upper_case = self.icon(super::LOWER_UPPER_CASE);
+                    if search_toggle(ui, &mut self.case_sensitive, lower_upper_case)
+                        .on_hover_ui(|ui| {
+                            ui.label("Match Case");
+                        })
+                        .changed()
+                    {
+                        self.selection = Some(Selection::Search);
+                        self.update_search_text_and_shown_glyph_cache();
+                    }
+
+                    let name_badge = self.icon(super::NAME_BADGE);
                     if ui
                         .add_enabled_ui(!self.case_sensitive, |ui| {
-                            ui.toggle_value(&mut self.search_name, format!("{}", super::NAME_BADGE))
-                                .on_hover_ui(|ui| {
-                                    ui.label("Include Glyph Name in Search");
-                                })
+                            search_toggle(ui, &mut self.search_name, name_badge).on_hover_ui(|ui| {
+                                ui.label("Include Glyph Name in Search");
+                            })
+                        })
+                        .inner
+                        .changed()
+                    {
+                        self.selection = Some(Selection::Search);
+                        self.update_search_text_and_shown_glyph_cache();
+                    }
+
+                    if search_toggle(ui, &mut self.regex_search, ".*".to_string())
+                        .on_hover_ui(|ui| {
+                            ui.label("Regular Expression");
+                            ui.label("Match names against a pattern, e.g. “^latin.*letter.*a$”");
+                        })
+                        .changed()
+                    {
+                        self.selection = Some(Selection::Search);
+                        self.update_search_text_and_shown_glyph_cache();
+                    }
+
+                    if let Some(Err(error)) = &self.search_params.regex {
+                        ui.colored_label(ui.visuals().error_fg_color, "⚠")
+                            .on_hover_text(error.to_string());
+                    }
+
+                    let almost_equal = self.icon(super::ALMOST_EQUAL);
+                    if search_toggle(ui, &mut self.fuzzy_search, almost_equal)
+                        .on_hover_ui(|ui| {
+                            ui.label("Fuzzy Matching");
+                            ui.label(
+                                "Also match names by block or initials, e.g. “lsla” for “LATIN \
+                                 SMALL LETTER A”, and look-alike characters",
+                            );
                         })
-                        .response
                         .changed()
                     {
-                        self.selected_category = 2;
+                        self.selection = Some(Selection::Search);
                         self.update_search_text_and_shown_glyph_cache();
                     }
                 });
@@ -673,26 +2124,36 @@ impl eframe::App for GlyphanaApp {
                     //.spacing([40.0, 4.0])
                     .striped(true)
                     .show(ui, |ui| {*/
-                    if ui
-                        .selectable_value(&mut self.selected_category, 0, "Recently Used")
+                    if self
+                        .selection_button(ui, Selection::RecentlyUsed, "Recently Used")
                         .changed()
                     {
                         self.update_search_text_and_shown_glyph_cache();
                     }
                     // ui.end_row();
 
-                    if ui
-                        .selectable_value(&mut self.selected_category, 1, "Collection")
+                    let collection_name = self.active_collection().name.clone();
+                    if self
+                        .selection_button(ui, Selection::Collection, collection_name)
                         .changed()
                     {
                         self.update_search_text_and_shown_glyph_cache();
                     }
                     // ui.end_row();
 
+                    if self
+                        .selection_button(ui, Selection::ClipboardHistory, "Clipboard History")
+                        .changed()
+                    {
+                        self.update_search_text_and_shown_glyph_cache();
+                    }
+
+                    let search_enabled = !self.ui_search_text.is_empty();
                     if ui
-                        .add_enabled(!self.ui_search_text.is_empty(), |ui: &mut egui::Ui| {
-                            ui.selectable_value(&mut self.selected_category, 2, "Search")
+                        .add_enabled_ui(search_enabled, |ui| {
+                            self.selection_button(ui, Selection::Search, "Search")
                         })
+                        .inner
                         .changed()
                     {
                         self.update_search_text_and_shown_glyph_cache();
@@ -707,15 +2168,69 @@ impl eframe::App for GlyphanaApp {
                     .striped(true)
                     .show(ui, |ui| {*/
                     let categories = self.categories.clone();
-                    for (i, category) in &mut categories.iter().enumerate() {
-                        if ui
-                            .selectable_value(
-                                &mut self.selected_category,
-                                i + CAT_START,
-                                &category.0,
-                            )
-                            .changed()
-                        {
+                    let push_pin = self.icon(super::PUSH_PIN);
+                    for i in self.category_order() {
+                        let category = &categories[i];
+                        let excluded = Some(i) == self.search_excluded_category;
+                        let pinned = self.pinned_categories.contains(&category.0);
+
+                        let mut name = egui::RichText::new(if pinned {
+                            format!("{push_pin} {}", category.0)
+                        } else {
+                            category.0.clone()
+                        });
+                        if excluded {
+                            name = name.strikethrough();
+                        }
+
+                        let response = self
+                            .selection_button(ui, Selection::Category(i), name)
+                            .context_menu(|ui| {
+                                let label = if excluded {
+                                    "Include in Search"
+                                } else {
+                                    "Exclude from Search"
+                                };
+
+                                if ui.button(label).clicked() {
+                                    ui.close_menu();
+                                    self.search_excluded_category =
+                                        if excluded { None } else { Some(i) };
+                                    self.update_search_text_and_shown_glyph_cache();
+                                }
+
+                                let label = if pinned { "Unpin" } else { "Pin to Top" };
+                                if ui.button(label).clicked() {
+                                    ui.close_menu();
+                                    if pinned {
+                                        self.pinned_categories.retain(|name| *name != category.0);
+                                    } else {
+                                        self.pinned_categories.push(category.0.clone());
+                                    }
+                                }
+
+                                // Usage decides the order of unpinned ones then.
+                                let movable = pinned || !self.sort_categories_by_usage;
+                                if ui
+                                    .add_enabled(movable, egui::Button::new("Move Up"))
+                                    .clicked()
+                                {
+                                    ui.close_menu();
+                                    self.move_category(i, true);
+                                }
+                                if ui
+                                    .add_enabled(movable, egui::Button::new("Move Down"))
+                                    .clicked()
+                                {
+                                    ui.close_menu();
+                                    self.move_category(i, false);
+                                }
+                            });
+
+                        if response.changed() {
+                            if Some(Selection::Category(i)) == self.selection {
+                                *self.category_usage.entry(category.0.clone()).or_default() += 1;
+                            }
                             self.update_search_text_and_shown_glyph_cache();
                         }
 
@@ -741,6 +2256,14 @@ impl eframe::App for GlyphanaApp {
         }*/
 
         egui::SidePanel::right("character_preview").show(ctx, |ui| {
+            // Nothing to measure, name or relate.
+            if !self.has_selected_char() {
+                ui.centered_and_justified(|ui| {
+                    ui.weak("Select a Glyph to Inspect It");
+                });
+                return;
+            }
+
             //egui::ScrollArea::vertical().show(ui, |ui| {
             //let (id, rect) = ui.allocate_space(egui::vec2(50.0, 50.0));
 
@@ -767,17 +2290,72 @@ impl eframe::App for GlyphanaApp {
 
             self.paint_glyph(scale, ui, response, painter);
 
-            ui.with_layout(
-                egui::Layout::top_down_justified(egui::Align::Center),
-                |ui| {
-                    egui::Grid::new("glyph_name")
-                        .num_columns(1)
-                        //.min_col_width(scale)
-                        .min_row_height(60.0)
-                        //.spacing([40.0, 4.0])
-                        .striped(true)
-                        .show(ui, |ui| {
-                            ui.end_row();
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.show_glyph_bounds, "Bounding Box & Origin")
+                    .on_hover_text("Show the Glyph's Ink Bounds, Pen Origin and Advance");
+
+                ui.toggle_value(&mut self.preview_monospace, "Mono")
+                    .on_hover_text("Preview with the Monospace Font");
+
+                let push_pin = self.icon(super::PUSH_PIN);
+                ui.toggle_value(&mut self.pin_inspector, push_pin)
+                    .on_hover_text("Pin the Inspector to this Glyph");
+            });
+
+            if !self.shown_glyph_cache.contains_key(&self.selected_char) {
+                ui.horizontal(|ui| {
+                    if self.full_glyph_cache.contains_key(&self.selected_char) {
+                        ui.weak("Not in Current View");
+
+                        if ui
+                            .small_button("Reveal")
+                            .on_hover_text("Show a Category with this Glyph")
+                            .clicked()
+                        {
+                            self.reveal_selected_char();
+                        }
+                    } else {
+                        ui.weak("Not in Font");
+                    }
+                });
+            }
+
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.sample_text)
+                        .hint_text("Sample Text")
+                        .desired_width(0.5 * scale),
+                )
+                .on_hover_text("Preview a Word instead of the Glyph");
+
+                if ui.small_button("ABC").on_hover_text("All Caps").clicked() {
+                    self.sample_text = self.sample_text.to_uppercase();
+                }
+
+                if ui.small_button("abc").on_hover_text("Lower Case").clicked() {
+                    self.sample_text = self.sample_text.to_lowercase();
+                }
+
+                if ui
+                    .small_button("ꜱᴍᴀʟʟ ᴄᴀᴘꜱ")
+                    .on_hover_text("Small Capitals")
+                    .clicked()
+                {
+                    self.sample_text = variants::small_caps(&self.sample_text);
+                }
+            });
+
+            ui.with_layout(
+                egui::Layout::top_down_justified(egui::Align::Center),
+                |ui| {
+                    egui::Grid::new("glyph_name")
+                        .num_columns(1)
+                        //.min_col_width(scale)
+                        .min_row_height(60.0)
+                        //.spacing([40.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.end_row();
                             ui.centered_and_justified(|ui| {
                                 let name = textwrap::wrap(
                                     &title_case(
@@ -793,6 +2371,38 @@ impl eframe::App for GlyphanaApp {
                             });
 
                             ui.end_row();
+
+                            #[cfg(feature = "speech")]
+                            if let Some(tts) = &mut self.tts {
+                                ui.centered_and_justified(|ui| {
+                                    if ui.small_button("🔊 Speak Name").clicked() {
+                                        if let Err(error) =
+                                            tts.speak(char_name(self.selected_char), true)
+                                        {
+                                            log::error!("Could not speak name: {error}");
+                                        }
+                                    }
+                                });
+                                ui.end_row();
+                            }
+
+                            for correction in name_correction::name_corrections(self.selected_char)
+                            {
+                                ui.centered_and_justified(|ui| {
+                                    ui.label(
+                                        egui::RichText::new(format!(
+                                            "Correct Name: {}",
+                                            title_case(correction)
+                                        ))
+                                        .small()
+                                        .weak(),
+                                    )
+                                    .on_hover_text(
+                                        "The Name above has an Error but Unicode Names Never Change",
+                                    );
+                                });
+                                ui.end_row();
+                            }
                         });
 
                     egui::Grid::new("flip_case")
@@ -845,258 +2455,2911 @@ impl eframe::App for GlyphanaApp {
                                 ui.label("Unicode");
                             });
 
-                            let unicode_hex: [u8; 4] = bytemuck::cast(self.selected_char);
+                            let unicode_hex_string_ui =
+                                self.codepoint_padding.format(self.selected_char);
+                            let unicode_html_string =
+                                format!("&#x{:X};", self.selected_char as u32);
+
+                            if ui
+                                .button(egui::RichText::new(unicode_hex_string_ui).monospace())
+                                .on_hover_ui(|ui| {
+                                    ui.label("Click to Copy Unicode as HTML");
+                                })
+                                .clicked()
+                            {
+                                self.copy_to_clipboard(
+                                    ui.ctx(),
+                                    [self.selected_char],
+                                    unicode_html_string,
+                                );
+                            }
+
+                            ui.end_row();
+
+                            // Utf8
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                                ui.label("UTF-8");
+                            });
+
+                            let mut utf_eight = [0u8; 4];
+                            encode_unicode::Utf8Char::new(self.selected_char)
+                                .to_slice(&mut utf_eight);
+
+                            let mut utf_eight_string_ui = String::new();
+                            let mut utf_eight_string = String::new();
+
+                            if 0 != utf_eight[3] {
+                                utf_eight_string_ui += &format!("{:02X}\u{2009}", utf_eight[3]);
+                                utf_eight_string += &format!("{:02X}", utf_eight[3]);
+                            }
+
+                            utf_eight[..2].iter().rev().for_each(|&uc| {
+                                if 0 != uc || !utf_eight_string.is_empty() {
+                                    utf_eight_string_ui += &format!("{uc:02X}\u{2009}");
+                                    utf_eight_string += &format!("{uc:02X}");
+                                }
+                            });
+
+                            if ui
+                                .button(egui::RichText::new(utf_eight_string_ui).monospace())
+                                .on_hover_ui(|ui| {
+                                    ui.label("Click to Copy UTF8 Code");
+                                })
+                                .clicked()
+                            {
+                                self.copy_to_clipboard(
+                                    ui.ctx(),
+                                    [self.selected_char],
+                                    utf_eight_string,
+                                );
+                            }
+
+                            ui.end_row();
+
+                            // Encoded lengths
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                                ui.label("UTF-8 Bytes");
+                            });
+                            ui.label(self.selected_char.len_utf8().to_string());
+                            ui.end_row();
+
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                                ui.label("UTF-16 Units");
+                            });
+                            ui.label(self.selected_char.len_utf16().to_string());
+                            ui.end_row();
+
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                                ui.label("Plane");
+                            });
+                            // Astral characters need a surrogate pair in UTF-16.
+                            let plane = self.selected_char as u32 >> 16;
+                            ui.label(if 0 == plane {
+                                "BMP".to_string()
+                            } else {
+                                format!("Astral ({plane})")
+                            });
+                            ui.end_row();
+
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                                ui.label("Script");
+                            });
+                            let script = self.selected_char.script();
+                            self.script_link(ui, script);
+                            ui.end_row();
+
+                            // Only worth a row if other scripts use it too.
+                            let extensions = self
+                                .selected_char
+                                .script_extension()
+                                .iter()
+                                .collect::<Vec<_>>();
+                            if extensions != [script] {
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::TOP),
+                                    |ui| {
+                                        ui.label("Script Extensions");
+                                    },
+                                );
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.spacing_mut().item_spacing.x = 0.0;
+                                    for (i, &script) in extensions.iter().enumerate() {
+                                        if 0 != i {
+                                            ui.label(", ");
+                                        }
+                                        self.script_link(ui, script);
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                        });
+
+                    egui::Grid::new("collect")
+                        .num_columns(1)
+                        //.min_col_width(scale)
+                        //.spacing([40.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.end_row();
+
+                            ui.centered_and_justified(|ui| {
+                                let is_in_collection = self
+                                    .active_collection()
+                                    .characters
+                                    .contains(&self.selected_char);
+
+                                if ui
+                                    .add(egui::SelectableLabel::new(is_in_collection, "Collect"))
+                                    .on_hover_ui(|ui: &mut egui::Ui| {
+                                        ui.label("Add Glyp to Collection");
+                                    })
+                                    .clicked()
+                                {
+                                    self.toggle_collection(self.selected_char);
+                                }
+                            });
+                            ui.end_row();
+
+                            ui.centered_and_justified(|ui| {
+                                ui.menu_button("📋 Copy As…", |ui| {
+                                    self.copy_format_buttons(ui, self.selected_char);
+                                });
+                            });
+                            ui.end_row();
+
+                            ui.horizontal(|ui| {
+                                ui.add(
+                                    egui::DragValue::new(&mut self.repeat_count)
+                                        .clamp_range(1..=usize::MAX),
+                                );
+
+                                let too_many = MAX_REPEAT_COUNT < self.repeat_count;
+                                if ui
+                                    .add_enabled(
+                                        !too_many,
+                                        egui::Button::new(format!("Copy ×{}", self.repeat_count)),
+                                    )
+                                    .on_hover_text("Copy the Glyph this many Times in a Row")
+                                    .clicked()
+                                {
+                                    self.copy_repeated(ui.ctx());
+                                }
+
+                                if too_many {
+                                    ui.colored_label(
+                                        ui.visuals().error_fg_color,
+                                        format!("At most {MAX_REPEAT_COUNT}"),
+                                    );
+                                }
+                            });
+                            ui.end_row();
+
+                            ui.centered_and_justified(|ui| {
+                                ui.menu_button("🖼 Info Card…", |ui| {
+                                    if ui.button("💾 Save as PNG…").clicked() {
+                                        ui.close_menu();
+                                        self.save_info_card();
+                                    }
+
+                                    if ui.button("📋 Copy Image").clicked() {
+                                        ui.close_menu();
+                                        self.copy_info_card();
+                                    }
+                                })
+                                .response
+                                .on_hover_text("The Glyph, its Name, Codepoint & Co. as an Image");
+                            });
+                            ui.end_row();
+                        });
+
+                    let decomposition = DecompositionNode::new(self.selected_char);
+                    let has_decomposition = !decomposition.children.is_empty();
+                    let has_name_derivation =
+                        derived_name::derived_name(self.selected_char).is_some();
+                    let has_variants = variants::base_of(self.selected_char).is_some();
+
+                    let section_count = 3
+                        + has_decomposition as usize
+                        + has_name_derivation as usize
+                        + has_variants as usize;
+                    let pressed = ctx.input_mut(|i| {
+                        INSPECTOR_SECTION_KEYS
+                            .iter()
+                            .take(section_count)
+                            .position(|&key| {
+                                i.consume_shortcut(&egui::KeyboardShortcut::new(
+                                    egui::Modifiers::COMMAND,
+                                    key,
+                                ))
+                            })
+                    });
+
+                    let mut index = 0..;
+
+                    self.inspector_section(
+                        ui,
+                        index.next().unwrap(),
+                        pressed,
+                        "Categories",
+                        |app, ui| app.render_glyph_categories(ui),
+                    );
+
+                    if has_decomposition {
+                        self.inspector_section(
+                            ui,
+                            index.next().unwrap(),
+                            pressed,
+                            "Decomposition",
+                            |app, ui| app.render_decomposition(ui, &decomposition),
+                        );
+                    }
+
+                    if has_name_derivation {
+                        self.inspector_section(
+                            ui,
+                            index.next().unwrap(),
+                            pressed,
+                            "Name Derivation",
+                            |app, ui| app.render_name_derivation(ui),
+                        );
+                    }
+
+                    if has_variants {
+                        self.inspector_section(
+                            ui,
+                            index.next().unwrap(),
+                            pressed,
+                            "Variants",
+                            |app, ui| app.render_variants(ui),
+                        );
+                    }
+
+                    self.inspector_section(
+                        ui,
+                        index.next().unwrap(),
+                        pressed,
+                        "Font Variations",
+                        |app, ui| app.render_font_variations(ui),
+                    );
+
+                    self.inspector_section(
+                        ui,
+                        index.next().unwrap(),
+                        pressed,
+                        "Similar-Looking",
+                        |app, ui| app.render_similar_glyphs(ui),
+                    );
+
+                    self.inspector_section(
+                        ui,
+                        index.next().unwrap(),
+                        pressed,
+                        "Confusables",
+                        |app, ui| app.render_confusables(ui),
+                    );
+                },
+            );
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            if let (Some(coverage), Some((name, _))) = (
+                self.block_coverage
+                    .as_ref()
+                    .filter(|_| self.show_block_coverage),
+                self.selected_unicode_category(),
+            ) {
+                ui.label(format!(
+                    "{name}: {} shown, {} unassigned, {} missing from font",
+                    coverage.shown, coverage.unassigned, coverage.missing,
+                ));
+                ui.separator();
+            }
+
+            if Some(Selection::Collection) == self.selection {
+                self.render_collection_tabs(ui);
+                ui.separator();
+            }
+
+            ui.horizontal(|ui| {
+                self.render_codepoint_range(ui);
+
+                ui.separator();
+
+                let collection_name = self.active_collection().name.clone();
+                if ui
+                    .toggle_value(&mut self.only_collected, "Only Collected")
+                    .on_hover_text(format!("Only Show Glyphs in {collection_name}"))
+                    .changed()
+                {
+                    self.update_search_text_and_shown_glyph_cache();
+                }
+            });
+            ui.separator();
+
+            if Some(Selection::Search) == self.selection && 1 < self.search_block_counts.len() {
+                self.render_search_block_counts(ui);
+                ui.separator();
+            }
+
+            if Some(Selection::Search) == self.selection {
+                if let [(block, count)] = self.search_block_counts[..] {
+                    // Only if there is more to see.
+                    if self.block_glyph_count(block) > count {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("All results are in {}.", block.name()));
+                            if ui.button("Show Entire Block").clicked() {
+                                self.show_entire_block(block);
+                            }
+                        });
+                        ui.separator();
+                    }
+                }
+            }
+
+            self.render_glyph_grid(ui);
+        });
+
+        if false {
+            egui::Window::new("Window").show(ctx, |ui| {
+                ui.label("Windows can be moved by dragging them.");
+                ui.label("They are automatically sized based on contents.");
+                ui.label("You can turn on resizing and scrolling if you like.");
+                ui.label("You would normally choose either panels OR windows.");
+            });
+        }
+    }
+}
+
+// .auto_shrink([false;2])
+impl GlyphanaApp {
+    /// Applies the GUI zoom, changed via Ctrl+scroll, Ctrl+=, Ctrl+- and
+    /// Ctrl+0 (reset).
+    ///
+    /// We do this every frame as the native pixels per point can change, e.g.
+    /// when the window is moved to another display.
+    fn update_ui_zoom(&mut self, ctx: &egui::Context, native_pixels_per_point: Option<f32>) {
+        use egui::gui_zoom::kb_shortcuts;
+
+        if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::ZOOM_RESET)) {
+            self.ui_zoom = 1.0;
+        } else {
+            if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::ZOOM_IN)) {
+                self.ui_zoom += 0.1;
+            }
+            if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::ZOOM_OUT)) {
+                self.ui_zoom -= 0.1;
+            }
+            self.ui_zoom *= ctx.input(|i| i.zoom_delta());
+        }
+
+        self.ui_zoom = ((self.ui_zoom * 100.0).round() / 100.0).clamp(MIN_UI_ZOOM, MAX_UI_ZOOM);
+
+        let pixels_per_point = native_pixels_per_point.unwrap_or(1.0) * self.ui_zoom;
+        if pixels_per_point != ctx.pixels_per_point() {
+            ctx.set_pixels_per_point(pixels_per_point);
+        }
+
+        self.pixels_per_point = pixels_per_point;
+    }
+
+    fn render_glyph_grid(&mut self, ui: &mut egui::Ui) {
+        //info!("ã == a is {}", focaccia::unicode_full_case_eq("a", "ã"));
+
+        // The glyphs of the current view, in order, with their opacity.
+        let glyphs = if Some(Selection::RecentlyUsed) == self.selection {
+            // Most recently used first, fading out towards the oldest.
+            let mut seen = HashSet::new();
+            let recently_used = self
+                .recently_used
+                .iter()
+                .rev()
+                .filter(|&&chr| {
+                    self.full_glyph_cache.contains_key(&chr)
+                        && self.passes_view_filters(chr)
+                        && seen.insert(chr)
+                })
+                .copied()
+                .collect::<Vec<_>>();
+
+            let len = recently_used.len().max(2) as f32;
+            recently_used
+                .into_iter()
+                .enumerate()
+                .map(|(i, chr)| (chr, 1.0 - 0.7 * i as f32 / (len - 1.0)))
+                .collect::<Vec<_>>()
+        } else if Some(Selection::ClipboardHistory) == self.selection {
+            // In the order the glyphs were copied.
+            self.clipboard_history
+                .iter()
+                .filter(|&&chr| self.passes_view_filters(chr))
+                .map(|&chr| (chr, 1.0))
+                .collect()
+        } else if let Some(font_file) = self.font_file.as_ref().filter(|_| self.font_order) {
+            // Fonts are often organized by design, e.g. alternates follow
+            // their base glyph.
+            if self.glyph_ids.is_empty() {
+                self.glyph_ids = font_file.glyph_ids(self.full_glyph_cache.keys().copied());
+            }
+
+            let mut glyphs = self
+                .shown_glyph_cache
+                .keys()
+                .map(|&chr| (chr, 1.0))
+                .collect::<Vec<_>>();
+            glyphs.sort_by_key(|(chr, _)| self.glyph_ids.get(chr).copied().unwrap_or(u16::MAX));
+            glyphs
+        } else if self.is_showing_ranked_search() {
+            self.search_ranking
+                .iter()
+                .filter(|chr| self.shown_glyph_cache.contains_key(chr))
+                .map(|&chr| (chr, 1.0))
+                .collect()
+        } else {
+            self.shown_glyph_cache
+                .keys()
+                .map(|&chr| (chr, 1.0))
+                .collect()
+        };
+
+        let spacing = 2.0;
+        let cell_size = self.default_font_id.size * 2. + spacing;
+
+        let mut scroll_area = egui::ScrollArea::vertical();
+        if let Some(offset) = self.pending_grid_scroll_offset.take() {
+            scroll_area = scroll_area.vertical_scroll_offset(offset);
+        }
+
+        let output = scroll_area.show_viewport(ui, |ui, viewport| {
+            let columns = (((ui.available_width() + spacing) / cell_size) as usize).max(1);
+            let page = columns * ((viewport.height() / cell_size) as usize).max(1);
+
+            let scroll_to_selected = std::mem::take(&mut self.scroll_to_selected_char)
+                || (!self.pin_inspector && self.navigate_glyph_grid(ui, &glyphs, columns, page));
+
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing = egui::Vec2::splat(spacing);
+
+                for (chr, opacity) in glyphs {
+                    let response = self.glyph_button(ui, chr, opacity);
+
+                    if scroll_to_selected && chr == self.selected_char {
+                        response.scroll_to_me(None);
+                    }
+                }
+            });
+        });
+
+        self.grid_scroll_offset = output.state.offset.y;
+
+        // Keep where we are in the results of a remembered query.
+        if Some(Selection::Search) == self.selection {
+            let (selected_char, scroll_offset) = (self.selected_char, self.grid_scroll_offset);
+
+            if let Some(entry) = self
+                .search_history
+                .iter_mut()
+                .find(|entry| entry.query == self.ui_search_text)
+            {
+                entry.selected_char = selected_char;
+                entry.scroll_offset = scroll_offset;
+            }
+        }
+    }
+
+    /// Moves the selected glyph with the arrow, Home/End and Page Up/Down
+    /// keys. `page` is the number of glyphs visible at once.
+    ///
+    /// Returns `true` if the selection moved.
+    fn navigate_glyph_grid(
+        &mut self,
+        ui: &egui::Ui,
+        glyphs: &[(char, f32)],
+        columns: usize,
+        page: usize,
+    ) -> bool {
+        // Don't steal keys from the search field.
+        if glyphs.is_empty() || ui.memory(|m| m.focus().is_some()) {
+            return false;
+        }
+
+        let last = glyphs.len() - 1;
+        let current = glyphs
+            .iter()
+            .position(|&(chr, _)| chr == self.selected_char);
+
+        let index = ui.input(|i| {
+            let Some(current) = current else {
+                // Start from the first glyph if the selection is not in view.
+                return GRID_NAVIGATION_KEYS
+                    .iter()
+                    .any(|&key| i.key_pressed(key))
+                    .then_some(0);
+            };
+
+            if i.key_pressed(egui::Key::ArrowLeft) {
+                Some(current.saturating_sub(1))
+            } else if i.key_pressed(egui::Key::ArrowRight) {
+                Some((current + 1).min(last))
+            } else if i.key_pressed(egui::Key::ArrowUp) {
+                Some(current.saturating_sub(columns))
+            } else if i.key_pressed(egui::Key::ArrowDown) {
+                Some((current + columns).min(last))
+            } else if i.key_pressed(egui::Key::PageUp) {
+                Some(current.saturating_sub(page))
+            } else if i.key_pressed(egui::Key::PageDown) {
+                Some((current + page).min(last))
+            } else if i.key_pressed(egui::Key::Home) {
+                Some(0)
+            } else if i.key_pressed(egui::Key::End) {
+                Some(last)
+            } else {
+                None
+            }
+        });
+
+        match index {
+            Some(index) if Some(index) != current => {
+                self.selected_char = glyphs[index].0;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The font to render `chr` with in the grid: the glyph font or, if that
+    /// lacks it, the first family that has it.
+    fn glyph_font_id(&self, chr: char) -> egui::FontId {
+        egui::FontId::new(
+            self.default_font_id.size,
+            self.family_coverage.family_for(chr),
+        )
+    }
+
+    /// A glyph in the grid. Its text is faded by `opacity`.
+    fn glyph_button(&mut self, ui: &mut egui::Ui, chr: char, opacity: f32) -> egui::Response {
+        // Invisible characters are shown as a marker so they can be found.
+        let abbreviation = invisible_char_abbreviation(chr);
+
+        let crisp_texture = if self.crisp_glyphs && abbreviation.is_none() {
+            self.crisp_glyph_cache
+                .texture(ui.ctx(), chr, self.default_font_id.size)
+        } else {
+            None
+        };
+
+        let text = match (abbreviation, &crisp_texture) {
+            (Some(abbreviation), _) => egui::RichText::new(abbreviation)
+                .monospace()
+                .size(0.4 * self.default_font_id.size),
+            (None, Some(_)) => egui::RichText::new(""),
+            (None, None) => egui::RichText::new(chr.to_string()).font(self.glyph_font_id(chr)),
+        };
+
+        let text = if opacity < 1.0 {
+            text.color(ui.visuals().text_color().linear_multiply(opacity))
+        } else {
+            text
+        };
+
+        let button = egui::Button::new(text)
+            .frame(true)
+            .min_size(egui::Vec2::splat(self.default_font_id.size * 2.));
+
+        let button = if chr == self.selected_char || self.selected_chars.contains(&chr) {
+            button.fill(ui.visuals().selection.bg_fill)
+        } else {
+            button
+        };
+
+        let hover_button = ui
+            .add_sized(egui::Vec2::splat(self.default_font_id.size * 2.), button)
+            .context_menu(|ui| {
+                if ui
+                    .add_enabled(chr != self.selected_char, egui::Button::new("🔍 Inspect"))
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.selected_char = chr;
+                }
+
+                ui.separator();
+
+                if ui.button("📋 Copy").clicked() {
+                    ui.close_menu();
+                    self.copy_glyphs(ui.ctx(), vec![chr]);
+                }
+
+                ui.menu_button("📋 Copy As…", |ui| {
+                    self.copy_format_buttons(ui, chr);
+                });
+
+                if ui.button("Copy Name").clicked() {
+                    ui.close_menu();
+                    self.copy_to_clipboard(ui.ctx(), [chr], self.display_name(chr).to_uppercase());
+                }
+
+                ui.separator();
+
+                let collection = self.active_collection();
+                let label = if collection.characters.contains(&chr) {
+                    format!("Remove from {}", collection.name)
+                } else {
+                    format!("Add to {}", collection.name)
+                };
+
+                if ui.button(label).clicked() {
+                    ui.close_menu();
+                    self.toggle_collection(chr);
+                }
+
+                if let Some(block) = ub::find_unicode_block(chr) {
+                    if ui
+                        .button(format!("Browse {}", block.name()))
+                        .on_hover_text("Show Every Glyph of the Block")
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.show_entire_block(block);
+                    }
+                }
+
+                if ui
+                    .button("Hide This Character")
+                    .on_hover_text("Unhide It in the Preferences")
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.hide_char(chr);
+                }
+
+                ui.separator();
+
+                if ui.button("Copy All Shown").clicked() {
+                    ui.close_menu();
+                    let shown = self.shown_glyph_cache.keys().copied().collect();
+                    self.copy_glyphs(ui.ctx(), shown);
+                }
+
+                if !self.selected_chars.is_empty() {
+                    if ui.button("Copy Selection").clicked() {
+                        ui.close_menu();
+                        let selection = self.selected_chars.iter().copied().collect();
+                        self.copy_glyphs(ui.ctx(), selection);
+                    }
+
+                    if ui.button("Copy Selection as Markdown Table").clicked() {
+                        ui.close_menu();
+                        let selection = self.selected_chars.clone();
+                        self.copy_to_clipboard(
+                            ui.ctx(),
+                            selection.iter().copied(),
+                            copy_format::markdown_table(
+                                selection.iter().map(|&chr| (chr, self.display_name(chr))),
+                                self.codepoint_padding,
+                            ),
+                        );
+                    }
+                }
+            });
+
+        let tooltip_ui = |ui: &mut egui::Ui| {
+            ui.label(egui::RichText::new(chr.to_string()).font(self.glyph_font_id(chr)));
+
+            let details = if self.show_tooltip_details {
+                format!(
+                    "\nDecimal {}\nUTF-8 {}",
+                    chr as u32,
+                    chr.to_string()
+                        .bytes()
+                        .map(|byte| format!("{byte:02X}"))
+                        .collect::<Vec<_>>()
+                        .join("\u{2009}")
+                )
+            } else {
+                String::new()
+            };
+
+            ui.label(format!(
+                "{}\n{}{details}\n\nDouble-click to copy 📋\nCtrl/⌘-click to select several",
+                capitalize(
+                    self.full_glyph_cache
+                        .get(&chr)
+                        .map(|name| name.as_str())
+                        .unwrap_or_default()
+                ),
+                self.codepoint_padding.format(chr)
+            ));
+        };
+
+        let hover_button = if self.show_glyph_tooltips {
+            hover_button.on_hover_ui(tooltip_ui)
+        } else {
+            hover_button
+        };
+
+        if self.active_collection().characters.contains(&chr) {
+            let rect = hover_button.rect;
+            let color = ui.visuals().warn_fg_color;
+
+            match self.collection_marker {
+                CollectionMarker::None => (),
+                CollectionMarker::Border => {
+                    ui.painter().rect_stroke(
+                        rect.shrink(1.0),
+                        ui.visuals().widgets.inactive.rounding,
+                        egui::Stroke::new(2.0_f32, color),
+                    );
+                }
+                CollectionMarker::Corner => {
+                    let size = 0.4 * self.default_font_id.size;
+                    ui.painter().add(egui::Shape::convex_polygon(
+                        vec![
+                            rect.right_top(),
+                            rect.right_top() + egui::Vec2::new(0.0, size),
+                            rect.right_top() - egui::Vec2::new(size, 0.0),
+                        ],
+                        color,
+                        egui::Stroke::NONE,
+                    ));
+                }
+            }
+        }
+
+        if abbreviation.is_some() {
+            let rect = hover_button.rect.shrink(0.25 * self.default_font_id.size);
+            let mut stroke = ui.style().interact(&hover_button).fg_stroke;
+            stroke.color = stroke.color.linear_multiply(0.5 * opacity);
+
+            ui.painter().extend(egui::Shape::dashed_line(
+                &[
+                    rect.left_top(),
+                    rect.right_top(),
+                    rect.right_bottom(),
+                    rect.left_bottom(),
+                    rect.left_top(),
+                ],
+                stroke,
+                2.0,
+                2.0,
+            ));
+        }
+
+        if let Some(texture) = crisp_texture {
+            paint_crisp_glyph(
+                ui.painter(),
+                &texture,
+                hover_button.rect.center(),
+                egui::Align2::CENTER_CENTER,
+                ui.style()
+                    .interact(&hover_button)
+                    .text_color()
+                    .linear_multiply(opacity),
+            );
+        }
+
+        if hover_button.double_clicked() {
+            // Send to clipboard.
+            self.copy_to_clipboard(ui.ctx(), [chr], chr.to_string());
+
+            /*use enigo::KeyboardControllable;
+            let mut enigo = enigo::Enigo::new();
+            let alt_tab = "{+ALT}{TAB}{-ALT}".to_string();
+            enigo.key_sequence_parse(&(alt_tab.clone() + &chr.to_string() + &alt_tab))
+            */
+        } else if hover_button.clicked() {
+            if ui.input(|i| i.modifiers.command) {
+                // The glyph clicked before starts the selection.
+                if self.selected_chars.is_empty() && self.has_selected_char() {
+                    self.selected_chars.insert(self.selected_char);
+                }
+
+                if !self.selected_chars.remove(&chr) {
+                    self.selected_chars.insert(chr);
+                }
+            } else {
+                self.selected_chars.clear();
+            }
+
+            if !self.pin_inspector {
+                self.selected_char = chr;
+            }
+
+            self.recently_used.push_back(chr);
+            if self.recently_used_max_len < self.recently_used.len() {
+                self.recently_used.pop_front();
+            }
+        }
+
+        hover_button
+    }
+
+    /// Applies the property category settings to the (not persisted)
+    /// categories.
+    fn update_property_categories(&mut self) {
+        for (_, category) in &mut self.categories {
+            if let UnicodeCategory::Property(property) = category {
+                property.exclude_emoji = self.exclude_emoji_from_properties;
+            }
+        }
+
+        self.glyph_categories = None;
+        self.search_excluded_chars = None;
+    }
+
+    /// The name of `chr` as shown and copied, with the codepoint of a
+    /// character without a name padded as set in the preferences.
+    fn display_name(&self, chr: char) -> String {
+        known_char_name(chr).unwrap_or_else(|| self.codepoint_padding.format(chr))
+    }
+
+    fn active_collection(&self) -> &Collection {
+        &self.collections[self.active_collection]
+    }
+
+    /// Deletes the collection at `index`. There is always one left.
+    fn delete_collection(&mut self, index: usize) {
+        self.collection_to_delete = None;
+        if self.collections.len() <= 1 || self.collections.len() <= index {
+            return;
+        }
+
+        self.collections.remove(index);
+        if index < self.active_collection {
+            self.active_collection -= 1;
+        }
+        self.active_collection = self.active_collection.min(self.collections.len() - 1);
+
+        // The active collection may be a different one now even if its index
+        // is the same.
+        self.update_search_text_and_shown_glyph_cache();
+    }
+
+    /// Adds `chr` to the active collection or removes it if it is already in
+    /// there.
+    fn toggle_collection(&mut self, chr: char) {
+        let characters = &mut self.collections[self.active_collection].characters;
+        if !characters.remove(&chr) {
+            characters.insert(chr);
+        }
+
+        if self.only_collected || Some(Selection::Collection) == self.selection {
+            self.update_search_text_and_shown_glyph_cache();
+        }
+    }
+
+    /// A "Copy …" button for each [`CopyFormat`].
+    fn copy_format_buttons(&mut self, ui: &mut egui::Ui, chr: char) {
+        for format in CopyFormat::ALL {
+            if ui.button(format!("Copy {}", format.label())).clicked() {
+                ui.close_menu();
+                let text = format.format(chr, &self.display_name(chr), self.codepoint_padding);
+                self.copy_to_clipboard(ui.ctx(), [chr], text);
+            }
+        }
+    }
+
+    /// Copies `glyphs`, joined by the [`Separator`] from the settings. All
+    /// copy actions of several glyphs go through here.
+    fn copy_glyphs(&mut self, ctx: &egui::Context, glyphs: Vec<char>) {
+        let text = self.copy_separator.join(glyphs.iter().copied());
+        self.copy_to_clipboard(ctx, glyphs, text);
+    }
+
+    /// Copies the glyph the inspector shows, or says there is none.
+    fn copy_selected_char(&mut self, ctx: &egui::Context) {
+        if self.has_selected_char() {
+            let chr = self.selected_char;
+            self.copy_to_clipboard(ctx, [chr], chr.to_string());
+        } else {
+            self.show_toast(ctx, "Select a Glyph to Copy First".to_string());
+        }
+    }
+
+    /// Sends `text` to the clipboard. All copy actions go through here.
+    ///
+    /// `chars` are the glyphs `text` was created from and are recorded in
+    /// the clipboard history.
+    fn copy_to_clipboard(
+        &mut self,
+        ctx: &egui::Context,
+        chars: impl IntoIterator<Item = char>,
+        text: String,
+    ) {
+        ctx.output_mut(|o| o.copied_text = text);
+        self.show_toast(ctx, "📋 Copied".to_string());
+
+        if self.record_clipboard_history {
+            for chr in chars {
+                if !self.clipboard_history.contains(&chr) {
+                    self.clipboard_history.push(chr);
+                }
+            }
+        }
+    }
+
+    /// Whether a glyph is selected for the inspector. Nothing is before the
+    /// user clicks one for the first time.
+    fn has_selected_char(&self) -> bool {
+        NO_SELECTED_CHAR != self.selected_char
+    }
+
+    /// Copies the selected glyph [`Self::repeat_count`] times in a row, e.g.
+    /// for separators.
+    fn copy_repeated(&mut self, ctx: &egui::Context) {
+        let (chr, count) = (self.selected_char, self.repeat_count);
+
+        self.copy_to_clipboard(ctx, [chr], chr.to_string().repeat(count));
+        self.show_toast(ctx, format!("📋 Copied {chr} ×{count}"));
+    }
+
+    /// E.g. `Glyphana — 💩 U+1F4A9` or just `Glyphana` without `chr`.
+    /// Characters title bars may not render, like invisible ones, controls
+    /// and marks, are left out; their codepoint is enough.
+    fn window_title(&self, chr: Option<char>) -> String {
+        match chr {
+            None => "Glyphana".to_string(),
+            Some(chr) => {
+                let codepoint = self.codepoint_padding.format(chr);
+                if is_deceptive(chr) || chr.is_control() || chr.is_mark() {
+                    format!("Glyphana — {codepoint}")
+                } else {
+                    format!("Glyphana — {chr} {codepoint}")
+                }
+            }
+        }
+    }
+
+    /// Shows `text` at the bottom of the window for a moment.
+    fn show_toast(&mut self, ctx: &egui::Context, text: String) {
+        self.toast = Some((text, ctx.input(|i| i.time)));
+    }
+
+    fn render_toast(&mut self, ctx: &egui::Context) {
+        let Some((text, since)) = &self.toast else {
+            return;
+        };
+
+        let remaining = TOAST_DURATION - (ctx.input(|i| i.time) - since);
+        if remaining <= 0.0 {
+            self.toast = None;
+            return;
+        }
+
+        egui::Area::new("toast")
+            .anchor(egui::Align2::CENTER_BOTTOM, egui::Vec2::new(0.0, -24.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(text.as_str());
+                });
+            });
+
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+    }
+
+    /// The family the large glyph preview in the inspector is rendered with.
+    fn preview_font_family(&self) -> egui::FontFamily {
+        if self.preview_monospace {
+            egui::FontFamily::Monospace
+        } else {
+            self.default_font_id.family.clone()
+        }
+    }
+
+    /// The rasterizer matching [`Self::preview_font_family()`].
+    fn preview_rasterizer(&self) -> &GlyphRasterizer {
+        if self.preview_monospace {
+            &self.monospace_rasterizer
+        } else {
+            self.crisp_glyph_cache.rasterizer()
+        }
+    }
+
+    /// The family the glyph grid and preview are rendered with.
+    fn glyph_font_family(&self) -> egui::FontFamily {
+        egui::FontFamily::Name(
+            match self.font_file {
+                Some(_) => OPENED_FONT,
+                None => NOTO_SANS,
+            }
+            .into(),
+        )
+    }
+
+    /// What the glyphs in the grid come from with the current fonts.
+    fn character_source(&self) -> CharacterSource {
+        match &self.font_file {
+            Some(font_file) => CharacterSource::FontFace(font_file.path.clone(), font_file.face),
+            None => CharacterSource::Bundled,
+        }
+    }
+
+    fn open_font(&mut self, ctx: &egui::Context) {
+        if let Some(path) = rfd::FileDialog::new()
+            .add_filter("Fonts", &["ttf", "otf", "ttc", "otc"])
+            .pick_file()
+        {
+            match FontFile::open(path.clone()) {
+                Ok(font_file) => {
+                    // The file may have changed since we cached its glyphs.
+                    let is_stale = |source: &CharacterSource| match source {
+                        CharacterSource::FontFace(cached, _) => *cached == path,
+                        CharacterSource::Bundled => false,
+                    };
+
+                    self.stashed_glyph_caches
+                        .retain(|source, _| !is_stale(source));
+                    if is_stale(&self.full_glyph_cache_source) {
+                        self.full_glyph_cache.clear();
+                    }
+
+                    self.font_file = Some(font_file);
+                    self.set_fonts(ctx);
+                }
+                Err(error) => log::error!("Could not open font {path:?}: {error}"),
+            }
+        }
+    }
+
+    fn set_font_face(&mut self, ctx: &egui::Context, face: usize) {
+        if let Some(font_file) = &mut self.font_file {
+            font_file.face = face;
+            self.set_fonts(ctx);
+        }
+    }
+
+    /// Registers the opened font's current face, if any, next to our own
+    /// fonts. The glyph caches are rebuilt once egui picked them up.
+    fn set_fonts(&mut self, ctx: &egui::Context) {
+        let mut fonts = self.font_definitions();
+
+        if let Some(font_file) = &self.font_file {
+            fonts.font_data.insert(
+                OPENED_FONT.to_owned(),
+                egui::FontData {
+                    font: font_file.data.clone().into(),
+                    index: font_file.current_face().index,
+                    tweak: Default::default(),
+                },
+            );
+            fonts.families.insert(
+                egui::FontFamily::Name(OPENED_FONT.into()),
+                vec![OPENED_FONT.to_owned()],
+            );
+        }
+
+        ctx.set_fonts(fonts.clone());
+        self.pending_fonts = Some(fonts);
+
+        // Now listing those of another face, or closed with the font.
+        if self.unencoded_glyphs.is_some() {
+            self.open_unencoded_glyphs();
+        }
+    }
+
+    /// Lists the glyphs of the opened font's current face no character maps
+    /// to. See [`FontFile::unencoded_glyphs()`].
+    fn open_unencoded_glyphs(&mut self) {
+        self.unencoded_glyphs = self.font_file.as_ref().and_then(|font_file| {
+            let font = rusttype::Font::try_from_vec_and_index(
+                font_file.data.clone(),
+                font_file.current_face().index,
+            )?;
+
+            Some(UnencodedGlyphs {
+                glyphs: font_file.unencoded_glyphs(),
+                font: RasterFont {
+                    name: OPENED_FONT.to_owned(),
+                    scale: 1.0,
+                    font,
+                },
+                textures: Default::default(),
+            })
+        });
+    }
+
+    /// Our fonts plus the enabled extra fonts, which are tried after ours.
+    fn font_definitions(&self) -> egui::FontDefinitions {
+        let mut fonts = Self::fonts(self.prefer_emoji_icon_font);
+
+        for (extra_font, data) in &self.extra_fonts {
+            let Some(data) = data else {
+                continue;
+            };
+
+            fonts.font_data.insert(
+                extra_font.name.clone(),
+                egui::FontData::from_owned(data.clone()),
+            );
+            fonts
+                .families
+                .entry(egui::FontFamily::Name(NOTO_SANS.into()))
+                .or_default()
+                .push(extra_font.name.clone());
+            fonts.families.insert(
+                egui::FontFamily::Name(extra_font.name.as_str().into()),
+                vec![extra_font.name.clone()],
+            );
+        }
+
+        fonts
+    }
+
+    /// Lists the fonts in [`ExtraFont::directory()`] and reads the enabled
+    /// ones.
+    fn scan_extra_fonts(&mut self) {
+        let Some(directory) = ExtraFont::directory() else {
+            return;
+        };
+
+        let extra_fonts = match ExtraFont::list(&directory) {
+            Ok(extra_fonts) => extra_fonts,
+            Err(error) => {
+                log::error!("Could not list extra fonts in {directory:?}: {error}");
+                Vec::new()
+            }
+        };
+
+        self.extra_fonts = extra_fonts
+            .into_iter()
+            .map(|extra_font| {
+                let data = self
+                    .enabled_extra_fonts
+                    .contains(&extra_font.name)
+                    .then(|| read_font(&extra_font.path))
+                    .flatten();
+                (extra_font, data)
+            })
+            .collect();
+    }
+
+    /// Registers the extra font at `index` into `extra_fonts` or removes it
+    /// again. The glyphs of our fonts are collected anew.
+    fn set_extra_font_enabled(&mut self, ctx: &egui::Context, index: usize, enabled: bool) {
+        let (extra_font, data) = &mut self.extra_fonts[index];
+
+        if enabled {
+            *data = read_font(&extra_font.path);
+            if data.is_none() {
+                return;
+            }
+            self.enabled_extra_fonts.insert(extra_font.name.clone());
+        } else {
+            *data = None;
+            self.enabled_extra_fonts.remove(&extra_font.name);
+        }
+
+        self.set_extra_fonts(ctx);
+    }
+
+    /// Registers the current `extra_fonts` with egui. The glyphs of our fonts
+    /// are collected anew.
+    fn set_extra_fonts(&mut self, ctx: &egui::Context) {
+        // Our fonts cover more or less now.
+        self.stashed_glyph_caches.remove(&CharacterSource::Bundled);
+        if CharacterSource::Bundled == self.full_glyph_cache_source {
+            self.full_glyph_cache.clear();
+        }
+
+        self.set_fonts(ctx);
+    }
+
+    /// Replaces the categories made from character set files with what is in
+    /// [`CharacterSet::directory()`] now.
+    fn load_character_sets(&mut self) {
+        self.categories
+            .truncate(self.categories.len() - self.character_set_count);
+        self.glyph_categories = None;
+        self.search_excluded_chars = None;
+        self.character_set_count = 0;
+        self.search_excluded_category = self
+            .search_excluded_category
+            .filter(|&index| index < self.categories.len());
+        self.character_set_errors.clear();
+
+        let Some(directory) = CharacterSet::directory() else {
+            return;
+        };
+
+        let (sets, errors) = CharacterSet::open_all(&directory);
+
+        self.character_set_count = sets.len();
+        self.categories.extend(sets.into_iter().map(|set| {
+            (
+                set.name,
+                UnicodeCategory::Collection(UnicodeCollection(set.characters)),
+            )
+        }));
+
+        self.character_set_errors = errors
+            .into_iter()
+            .map(|(path, error)| {
+                log::error!("Could not load character set {path:?}: {error}");
+                format!("{}: {error}", path.display())
+            })
+            .collect();
+    }
+
+    /// Saves the (persisted) app state, e.g. settings and collection, as JSON.
+    ///
+    /// Returns `false` if the user cancelled or the file could not be written.
+    fn export_app_state(&self) -> bool {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .set_file_name("glyphana.json")
+            .save_file()
+        else {
+            return false;
+        };
+
+        match serde_json::to_string_pretty(self)
+            .map_err(std::io::Error::from)
+            .and_then(|json| std::fs::write(&path, json))
+        {
+            Ok(()) => true,
+            Err(error) => {
+                log::error!("Could not export app state to {path:?}: {error}");
+                false
+            }
+        }
+    }
+
+    /// Puts the app back into its default state.
+    fn reset(&mut self, ctx: &egui::Context) {
+        *self = Self::default();
+        self.load_character_sets();
+        // Our fonts are not part of the app state but the glyph caches derived
+        // from them are.
+        self.set_fonts(ctx);
+    }
+
+    /// Saves the selected glyph rendered in every font that has it, labeled
+    /// with the font names, as a PNG.
+    fn save_contact_sheet(&self) {
+        let chr = self.selected_char;
+        let Some(sheet) = self
+            .crisp_glyph_cache
+            .rasterizer()
+            .contact_sheet(chr, 128.0)
+        else {
+            return;
+        };
+
+        if let Some(path) = rfd::FileDialog::new()
+            .add_filter("PNG", &["png"])
+            .set_file_name(format!("{}.png", self.codepoint_padding.format(chr)))
+            .save_file()
+        {
+            if let Err(error) = sheet.save_with_format(&path, image::ImageFormat::Png) {
+                log::error!("Could not save contact sheet to {path:?}: {error}");
+            }
+        }
+    }
+
+    /// Renders a card about the selected glyph, see [`raster::info_card()`].
+    fn info_card(&self) -> Option<image::RgbaImage> {
+        let chr = self.selected_char;
+        let text_rasterizer = self.crisp_glyph_cache.rasterizer();
+
+        // The glyph font may not have it, e.g. after opening a font.
+        let fallback_rasterizer;
+        let glyph_rasterizer = if text_rasterizer.font_for_char(chr).is_some() {
+            text_rasterizer
+        } else {
+            fallback_rasterizer = GlyphRasterizer::new(
+                &self.font_definitions(),
+                &self.family_coverage.family_for(chr),
+            );
+            &fallback_rasterizer
+        };
+
+        let plane = chr as u32 >> 16;
+        let rows = [
+            Some(("Codepoint", self.codepoint_padding.format(chr))),
+            ub::find_unicode_block(chr).map(|block| ("Block", block.name().to_string())),
+            Some(("Category", general_category_name(chr).to_string())),
+            Some((
+                "Plane",
+                if 0 == plane {
+                    "BMP".to_string()
+                } else {
+                    format!("Astral ({plane})")
+                },
+            )),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        raster::info_card(
+            glyph_rasterizer,
+            text_rasterizer,
+            chr,
+            &title_case(&self.display_name(chr)),
+            &rows,
+        )
+    }
+
+    fn save_info_card(&self) {
+        let Some(card) = self.info_card() else {
+            log::warn!("No font has a glyph for the info card");
+            return;
+        };
+
+        if let Some(path) = rfd::FileDialog::new()
+            .add_filter("PNG", &["png"])
+            .set_file_name(format!(
+                "{} Card.png",
+                self.codepoint_padding.format(self.selected_char)
+            ))
+            .save_file()
+        {
+            if let Err(error) = card.save_with_format(&path, image::ImageFormat::Png) {
+                log::error!("Could not save info card to {path:?}: {error}");
+            }
+        }
+    }
+
+    /// Puts the info card on the clipboard as an image. egui can only copy
+    /// text, so this talks to the clipboard directly.
+    fn copy_info_card(&self) {
+        let Some(card) = self.info_card() else {
+            log::warn!("No font has a glyph for the info card");
+            return;
+        };
+
+        let image = arboard::ImageData {
+            width: card.width() as _,
+            height: card.height() as _,
+            bytes: card.into_raw().into(),
+        };
+
+        if let Err(error) =
+            arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_image(image))
+        {
+            log::error!("Could not copy info card to the clipboard: {error}");
+        }
+    }
+
+    /// Saves, for each font the glyphs of the active collection come from, a
+    /// font with only those glyphs into a folder the user picks. See
+    /// [`subset_font::subset()`].
+    fn export_subset_fonts(&mut self) {
+        let Some(directory) = rfd::FileDialog::new().pick_folder() else {
+            return;
+        };
+
+        // The opened font or ours in fallback order, like the glyph grid.
+        let source_fonts = match &self.font_file {
+            Some(font_file) => vec![(
+                font_file.path.clone(),
+                std::borrow::Cow::Borrowed(font_file.data.as_slice()),
+                font_file.current_face().index,
+            )],
+            None => {
+                let mut definitions = self.font_definitions();
+                definitions
+                    .families
+                    .remove(&egui::FontFamily::Name(NOTO_SANS.into()))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|name| {
+                        let data = definitions.font_data.remove(&name)?;
+                        Some((PathBuf::from(name), data.font, data.index))
+                    })
+                    .collect()
+            }
+        };
+
+        let collection = self.active_collection();
+        let file_stem = collection.name.replace(['/', '\\'], "-");
+        let mut missing = collection.characters.iter().copied().collect::<Vec<_>>();
+        missing.sort_unstable();
+
+        let mut fonts = Vec::new();
+        for (name, data, index) in source_fonts {
+            if missing.is_empty() {
+                break;
+            }
+
+            let (chars, font) = match subset_font::subset(&data, index, &missing) {
+                Ok(subset) => subset,
+                Err(error) => {
+                    log::error!("Could not subset font {name:?}: {error}");
+                    continue;
+                }
+            };
+            if chars.is_empty() {
+                continue;
+            }
+
+            let extension = if font.starts_with(b"OTTO") {
+                "otf"
+            } else {
+                "ttf"
+            };
+            let font_name = name.file_stem().unwrap_or_default().to_string_lossy();
+            let path = directory.join(format!("{file_stem} ({font_name}).{extension}"));
+
+            if let Err(error) = std::fs::write(&path, font) {
+                log::error!("Could not save subset font to {path:?}: {error}");
+                continue;
+            }
+
+            missing.retain(|chr| chars.binary_search(chr).is_err());
+            fonts.push((path, chars.len()));
+        }
+
+        self.subset_font_export = Some(SubsetFontExport { fonts, missing });
+    }
+
+    fn render_subset_font_export(&self, ui: &mut egui::Ui) {
+        let Some(export) = &self.subset_font_export else {
+            return;
+        };
+
+        for (path, glyph_count) in &export.fonts {
+            ui.label(format!("{} ({glyph_count} glyphs)", path.display()));
+        }
+
+        if export.missing.is_empty() {
+            ui.label("Every glyph of the collection was included.");
+            return;
+        }
+
+        ui.separator();
+        ui.label(format!(
+            "No font has these {} glyphs of the collection:",
+            export.missing.len()
+        ));
+        ui.label(
+            egui::RichText::new(export.missing.iter().collect::<String>())
+                .font(self.default_font_id.clone()),
+        );
+    }
+
+    /// Saves the clipboard history as CSV or, for any other extension, as
+    /// plain text with one glyph per line.
+    fn export_clipboard_history(&self) {
+        if let Some(path) = rfd::FileDialog::new()
+            .add_filter("Text", &["txt"])
+            .add_filter("CSV", &["csv"])
+            .set_file_name("clipboard-history.txt")
+            .save_file()
+        {
+            let is_csv = path
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+
+            let mut contents = if is_csv {
+                String::from("Glyph,Unicode,Name\n")
+            } else {
+                String::new()
+            };
+
+            for &chr in &self.clipboard_history {
+                let name = title_case(&self.display_name(chr));
+                contents += &if is_csv {
+                    format!(
+                        "\"{}\",{},\"{}\"\n",
+                        chr.to_string().replace('"', "\"\""),
+                        self.codepoint_padding.format(chr),
+                        name.replace('"', "\"\"")
+                    )
+                } else {
+                    format!("{chr}\t{}\t{name}\n", self.codepoint_padding.format(chr))
+                };
+            }
+
+            if let Err(error) = std::fs::write(&path, contents) {
+                log::error!("Could not export clipboard history to {path:?}: {error}");
+            }
+        }
+    }
+
+    fn update_search_text_and_shown_glyph_cache(&mut self) {
+        // Update character cache.
+        if Some(Selection::Search) == self.selection {
+            //info!("Updating cache");
+            self.search_params = SearchParams::new(
+                &self.ui_search_text,
+                self.case_sensitive,
+                self.search_name,
+                self.ignore_diacritics,
+                self.include_deprecated,
+                self.stem_words,
+                self.fuzzy_search,
+            );
+            if self.regex_search {
+                self.search_params.set_regex(&self.ui_search_text);
+            }
+            if self.search_excluded_chars.as_ref().map(|(index, _)| *index)
+                != self.search_excluded_category
+            {
+                self.search_excluded_chars = self.search_excluded_category.and_then(|index| {
+                    let (_, category) = self.categories.get(index)?;
+                    Some((index, category.characters().into_iter().collect()))
+                });
+            }
+            self.search_params.excluded_chars = self
+                .search_excluded_chars
+                .as_ref()
+                .map(|(_, chars)| chars.clone())
+                .unwrap_or_default();
+
+            let results = SearchEngine::search(&self.full_glyph_cache, &self.search_params);
+
+            let mut ranking = results
+                .iter()
+                .map(|(&chr, name)| (chr, SearchEngine::relevance(chr, name, &self.search_params)))
+                .collect::<Vec<_>>();
+            // Stable, so equally relevant results stay in codepoint order.
+            ranking.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+            self.search_ranking = ranking.into_iter().map(|(chr, _)| chr).collect();
+
+            let mut block_counts = BTreeMap::<ub::UnicodeBlock, usize>::new();
+            for chr in results.keys() {
+                if let Some(block) = ub::find_unicode_block(*chr) {
+                    *block_counts.entry(block).or_default() += 1;
+                }
+            }
+            self.search_block_counts = block_counts.into_iter().collect();
+            self.search_block_counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+            // Drop a block filter the results have nothing in anymore.
+            self.search_block_filter = self.search_block_filter.filter(|block| {
+                self.search_block_counts
+                    .iter()
+                    .any(|(counted, _)| counted == block)
+            });
+
+            self.shown_glyph_cache = match self.search_block_filter {
+                Some(block) => results
+                    .into_iter()
+                    .filter(|(chr, _)| block.contains(*chr))
+                    .collect(),
+                None => results,
+            };
+        } else {
+            //info!("Updating full cache for category {}!", self.selected_category);
+            self.shown_glyph_cache = self
+                .full_glyph_cache
+                .clone()
+                .into_iter()
+                // Filter by category.
+                .filter(|(chr, _)| {
+                    match self.selection {
+                        Some(Selection::RecentlyUsed) => self.recently_used.contains(chr),
+                        Some(Selection::Collection) => {
+                            self.active_collection().characters.contains(chr)
+                        }
+                        Some(Selection::ClipboardHistory) => self.clipboard_history.contains(chr),
+                        Some(Selection::Search) => unreachable!(),
+                        Some(Selection::Category(index)) => self
+                            .categories
+                            .get(index)
+                            .is_some_and(|(_, category)| category.contains(*chr)),
+                        // If no category is selected display all glyphs in the font.
+                        None => true,
+                    }
+                })
+                .collect();
+
+            if Some(Selection::Collection) == self.selection {
+                self.filter_collection();
+            }
+        }
+
+        let shown_glyph_cache = std::mem::take(&mut self.shown_glyph_cache);
+        self.shown_glyph_cache = shown_glyph_cache
+            .into_iter()
+            .filter(|&(chr, _)| self.passes_view_filters(chr))
+            .collect();
+
+        if HiddenSelection::SelectFirstShown == self.hidden_selection
+            && !self.pin_inspector
+            && !self.shown_glyph_cache.contains_key(&self.selected_char)
+        {
+            if let Some(chr) = self.first_shown_char() {
+                self.selected_char = chr;
+            }
+        }
+
+        self.update_block_coverage();
+    }
+
+    /// Whether `chr` gets past what narrows every view: the codepoint range,
+    /// the hidden characters and Only Collected.
+    fn passes_view_filters(&self, chr: char) -> bool {
+        let in_range = match &self.codepoint_range {
+            Some(range) => range.contains(&chr),
+            None => true,
+        };
+
+        in_range
+            && !self.hidden_chars.contains(&chr)
+            && (!self.only_collected || self.active_collection().characters.contains(&chr))
+    }
+
+    /// Whether the glyph grid lists search results best first.
+    fn is_showing_ranked_search(&self) -> bool {
+        Some(Selection::Search) == self.selection && self.rank_search_results
+    }
+
+    /// The glyph the grid starts with, ignoring the codepoint range.
+    fn first_shown_char(&self) -> Option<char> {
+        if self.is_showing_ranked_search() {
+            self.search_ranking
+                .iter()
+                .find(|chr| self.shown_glyph_cache.contains_key(chr))
+                .copied()
+        } else {
+            self.shown_glyph_cache.keys().next().copied()
+        }
+    }
+
+    /// Switches to the first category with the selected glyph, or to all
+    /// glyphs if none has it, and scrolls to it.
+    fn reveal_selected_char(&mut self) {
+        let chr = self.selected_char;
+
+        self.selection = self
+            .categories
+            .iter()
+            .position(|(_, category)| category.contains(chr))
+            .map(Selection::Category);
+
+        if self
+            .codepoint_range
+            .as_ref()
+            .is_some_and(|range| !range.contains(&chr))
+        {
+            self.codepoint_range = None;
+            self.codepoint_range_text = Default::default();
+        }
+
+        self.update_search_text_and_shown_glyph_cache();
+        self.scroll_to_selected_char = true;
+    }
+
+    /// Puts the current query at the top of the search history.
+    fn remember_search(&mut self) {
+        if self.ui_search_text.is_empty() {
+            return;
+        }
+
+        self.search_history
+            .retain(|entry| entry.query != self.ui_search_text);
+        self.search_history.insert(
+            0,
+            SearchHistoryEntry {
+                query: self.ui_search_text.clone(),
+                selected_char: self.selected_char,
+                scroll_offset: self.grid_scroll_offset,
+            },
+        );
+        self.search_history.truncate(self.search_history_max_len);
+    }
+
+    /// Shows the name of `script`; clicking it browses the characters used
+    /// by that script.
+    fn script_link(&mut self, ui: &mut egui::Ui, script: Script) {
+        if ui
+            .link(script.full_name().replace('_', " "))
+            .on_hover_ui(|ui| {
+                ui.label("Click to Show All Characters of This Script");
+            })
+            .clicked()
+        {
+            self.ui_search_text = format!("script:{}", script.full_name());
+            self.selection = Some(Selection::Search);
+            self.update_search_text_and_shown_glyph_cache();
+        }
+    }
+
+    /// Keeps `chr` out of every view until the user unhides it in the
+    /// preferences.
+    fn hide_char(&mut self, chr: char) {
+        self.hidden_chars.insert(chr);
+        self.selected_chars.remove(&chr);
+        self.update_search_text_and_shown_glyph_cache();
+    }
+
+    /// Lists the remembered searches containing what is typed into
+    /// `search_field` below it while it is being edited. Clicking one runs it
+    /// again, see [`Self::rerun_search()`].
+    fn search_history_popup(&mut self, ui: &mut egui::Ui, search_field: &egui::Response) {
+        let popup_id = ui.make_persistent_id("search_history_popup");
+
+        let typed = self.ui_search_text.to_lowercase();
+        let matches = self
+            .search_history
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                entry.query != self.ui_search_text && entry.query.to_lowercase().contains(&typed)
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        if matches.is_empty() {
+            if ui.memory(|memory| memory.is_popup_open(popup_id)) {
+                ui.memory_mut(|memory| memory.close_popup());
+            }
+            return;
+        }
+
+        if search_field.gained_focus() || search_field.changed() {
+            ui.memory_mut(|memory| memory.open_popup(popup_id));
+        }
+
+        let mut clicked = None;
+        egui::popup_below_widget(ui, popup_id, search_field, |ui| {
+            for index in matches {
+                if ui
+                    .selectable_label(false, &self.search_history[index].query)
+                    .clicked()
+                {
+                    clicked = Some(index);
+                }
+            }
+        });
+
+        if let Some(index) = clicked {
+            ui.memory_mut(|memory| memory.close_popup());
+            self.rerun_search(index);
+        }
+    }
+
+    /// Runs the query of the search history entry at `index` again and
+    /// brings back the glyph selected and the scroll position in its results.
+    fn rerun_search(&mut self, index: usize) {
+        let entry = self.search_history.remove(index);
+
+        self.ui_search_text = entry.query.clone();
+        self.selection = Some(Selection::Search);
+        self.update_search_text_and_shown_glyph_cache();
+
+        self.selected_char = entry.selected_char;
+        self.pending_grid_scroll_offset = Some(entry.scroll_offset);
+
+        self.search_history.insert(0, entry);
+    }
+
+    /// Undoes one step of what the user selected, in this order: the search,
+    /// the glyphs selected with Ctrl/⌘-click, the category, the focus.
+    fn back_out(&mut self, ctx: &egui::Context) {
+        if !self.ui_search_text.is_empty() {
+            self.ui_search_text.clear();
+
+            if Some(Selection::Search) == self.selection {
+                self.selection = None;
+            }
+            self.update_search_text_and_shown_glyph_cache();
+        } else if !self.selected_chars.is_empty() {
+            self.selected_chars.clear();
+        } else if self.selection.is_some() {
+            self.selection = None;
+            self.update_search_text_and_shown_glyph_cache();
+        } else if let Some(id) = ctx.memory(|m| m.focus()) {
+            ctx.memory_mut(|m| m.surrender_focus(id));
+        }
+    }
+
+    /// The Unicode category the grid shows, if any.
+    fn selected_unicode_category(&self) -> Option<&(String, UnicodeCategory)> {
+        match self.selection {
+            Some(Selection::Category(index)) => self.categories.get(index),
+            _ => None,
+        }
+    }
+
+    /// Counts the codepoints of the selected category that are not shown, if
+    /// it is made up of whole blocks.
+    fn update_block_coverage(&mut self) {
+        self.block_coverage = self
+            .selected_unicode_category()
+            .filter(|(_, category)| {
+                matches!(
+                    category,
+                    UnicodeCategory::Block(_) | UnicodeCategory::MultiBlock(_)
+                )
+            })
+            .map(|(_, category)| {
+                let characters = category.characters();
+                let unassigned = characters.iter().filter(|chr| chr.is_unassigned()).count();
+
+                BlockCoverage {
+                    shown: self.shown_glyph_cache.len(),
+                    unassigned,
+                    missing: characters
+                        .len()
+                        .saturating_sub(unassigned + self.shown_glyph_cache.len()),
+                }
+            });
+    }
+
+    /// The glyph size of the selected category, if the user picked one while
+    /// it was selected, else the global one.
+    fn glyph_scale(&self) -> GlyphScale {
+        match self.selection {
+            Some(Selection::Category(index)) => self
+                .categories
+                .get(index)
+                .and_then(|(name, _)| self.category_glyph_scales.get(name))
+                .copied()
+                .unwrap_or(self.glyph_scale),
+            _ => self.glyph_scale,
+        }
+    }
+
+    /// Sets the glyph size of the selected category or, if none is, the
+    /// global one.
+    fn set_glyph_scale(&mut self, glyph_scale: GlyphScale) {
+        match self.selection.and_then(|selection| match selection {
+            Selection::Category(index) => self.categories.get(index),
+            _ => None,
+        }) {
+            Some((name, _)) => {
+                self.category_glyph_scales.insert(name.clone(), glyph_scale);
+            }
+            None => self.glyph_scale = glyph_scale,
+        }
+    }
+
+    /// An entry of the side panel that selects what the grid shows. Clicking
+    /// the selected one deselects it if the user wants that.
+    fn selection_button(
+        &mut self,
+        ui: &mut egui::Ui,
+        selection: Selection,
+        text: impl Into<egui::WidgetText>,
+    ) -> egui::Response {
+        let is_selected = Some(selection) == self.selection;
+        let mut response = ui.selectable_label(is_selected, text);
+
+        if response.clicked() {
+            if !is_selected {
+                self.selection = Some(selection);
+                response.mark_changed();
+            } else if self.click_to_deselect {
+                self.selection = None;
+                response.mark_changed();
+            }
+        }
+
+        response
+    }
+
+    /// The indices into `categories` in the order the side panel lists them:
+    /// pinned ones first, in the order they were pinned, then the rest,
+    /// most selected this session first if the user wants that.
+    fn category_order(&self) -> Vec<usize> {
+        let pinned = self.pinned_category_order();
+
+        let mut rest = self.unpinned_category_order();
+        if self.sort_categories_by_usage {
+            // Stable, so equally used ones keep their order.
+            rest.sort_by_key(|&index| {
+                std::cmp::Reverse(
+                    self.category_usage
+                        .get(&self.categories[index].0)
+                        .copied()
+                        .unwrap_or_default(),
+                )
+            });
+        }
+
+        pinned.into_iter().chain(rest).collect()
+    }
+
+    /// The indices into `categories` of the pinned ones, in the order they
+    /// were pinned.
+    fn pinned_category_order(&self) -> Vec<usize> {
+        self.pinned_categories
+            .iter()
+            .filter_map(|pinned| self.categories.iter().position(|(name, _)| name == pinned))
+            .collect()
+    }
+
+    /// The indices into `categories` of the unpinned ones, those the user
+    /// moved in `custom_category_order` first.
+    fn unpinned_category_order(&self) -> Vec<usize> {
+        let pinned = self.pinned_category_order();
+        let mut order = (0..self.categories.len())
+            .filter(|index| !pinned.contains(index))
+            .collect::<Vec<_>>();
+        // Stable, so the others keep their order.
+        order.sort_by_key(|&index| {
+            self.custom_category_order
+                .iter()
+                .position(|name| *name == self.categories[index].0)
+                .unwrap_or(usize::MAX)
+        });
+
+        order
+    }
+
+    /// Swaps the category at `index` with the one listed above or below it,
+    /// among the pinned or the other ones.
+    fn move_category(&mut self, index: usize, up: bool) {
+        let name = self.categories[index].0.clone();
+        let neighbor = |position: usize, len: usize| {
+            if up {
+                position.checked_sub(1)
+            } else {
+                Some(position + 1).filter(|&other| other < len)
+            }
+        };
+
+        if let Some(position) = self
+            .pinned_categories
+            .iter()
+            .position(|pinned| *pinned == name)
+        {
+            if let Some(other) = neighbor(position, self.pinned_categories.len()) {
+                self.pinned_categories.swap(position, other);
+            }
+            return;
+        }
+
+        let mut order = self
+            .unpinned_category_order()
+            .into_iter()
+            .map(|index| self.categories[index].0.clone())
+            .collect::<Vec<_>>();
+
+        let Some(position) = order.iter().position(|other| *other == name) else {
+            return;
+        };
+        if let Some(other) = neighbor(position, order.len()) {
+            order.swap(position, other);
+        }
+
+        // Keep the places of character sets that failed to load this time.
+        order.extend(
+            self.custom_category_order
+                .iter()
+                .filter(|name| !self.categories.iter().any(|(other, _)| other == *name))
+                .cloned(),
+        );
+        self.custom_category_order = order;
+    }
+
+    /// Lists how many search results fall into each block; clicking a block
+    /// narrows the results to it.
+    fn render_search_block_counts(&mut self, ui: &mut egui::Ui) {
+        let mut block_filter = self.search_block_filter;
+
+        egui::CollapsingHeader::new("Results by Block").show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                let total = self
+                    .search_block_counts
+                    .iter()
+                    .map(|(_, count)| count)
+                    .sum::<usize>();
+                ui.selectable_value(&mut block_filter, None, format!("All ({total})"));
+
+                for (block, count) in &self.search_block_counts {
+                    ui.selectable_value(
+                        &mut block_filter,
+                        Some(*block),
+                        format!("{} ({count})", block.name()),
+                    );
+                }
+            });
+        });
+
+        if block_filter != self.search_block_filter {
+            self.search_block_filter = block_filter;
+            self.update_search_text_and_shown_glyph_cache();
+        }
+    }
+
+    /// How many glyphs of the current font are in `block`.
+    fn block_glyph_count(&self, block: ub::UnicodeBlock) -> usize {
+        let (Some(start), Some(end)) = (char::from_u32(block.start()), char::from_u32(block.end()))
+        else {
+            return 0;
+        };
+
+        self.full_glyph_cache.range(start..=end).count()
+    }
+
+    /// Leaves the search for all glyphs of `block`: its category, if it has
+    /// one, limited to the block's codepoint range.
+    fn show_entire_block(&mut self, block: ub::UnicodeBlock) {
+        let (Some(start), Some(end)) = (char::from_u32(block.start()), char::from_u32(block.end()))
+        else {
+            return;
+        };
+
+        self.codepoint_range_text = (
+            format!("{:04X}", block.start()),
+            format!("{:04X}", block.end()),
+        );
+        self.codepoint_range = Some(start..=end);
+
+        self.selection = self
+            .categories
+            .iter()
+            .position(|(_, category)| match category {
+                UnicodeCategory::Block(category_block) => block == *category_block,
+                _ => false,
+            })
+            .map(Selection::Category);
+        self.scroll_to_selected_char = true;
+
+        self.update_search_text_and_shown_glyph_cache();
+    }
+
+    /// A tab for each collection to make it the active one, plus the
+    /// controls to add, rename and delete collections.
+    fn render_collection_tabs(&mut self, ui: &mut egui::Ui) {
+        let mut active_collection = self.active_collection;
+
+        ui.horizontal_wrapped(|ui| {
+            for (index, collection) in self.collections.iter().enumerate() {
+                ui.selectable_value(
+                    &mut active_collection,
+                    index,
+                    format!("{} ({})", collection.name, collection.characters.len()),
+                );
+            }
+
+            if ui.button("➕").on_hover_text("New Collection").clicked() {
+                self.collections.push(Collection::new(format!(
+                    "Collection {}",
+                    self.collections.len() + 1
+                )));
+                active_collection = self.collections.len() - 1;
+            }
+
+            if ui
+                .add_enabled(1 < self.collections.len(), egui::Button::new("🗑"))
+                .on_hover_text("Delete This Collection")
+                .clicked()
+            {
+                self.collection_to_delete = Some(active_collection);
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Name");
+            ui.text_edit_singleline(&mut self.collections[active_collection].name);
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Filter");
+            if ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.collection_filter)
+                        .hint_text("Name or Codepoint"),
+                )
+                .changed()
+            {
+                self.update_search_text_and_shown_glyph_cache();
+            }
+        });
+
+        if active_collection != self.active_collection {
+            self.active_collection = active_collection;
+            self.update_search_text_and_shown_glyph_cache();
+        }
+    }
+
+    /// Keeps the glyphs of the collection view that `collection_filter`
+    /// finds, as a search would, or whose codepoint contains it.
+    fn filter_collection(&mut self) {
+        if self.collection_filter.trim().is_empty() {
+            return;
+        }
+
+        let params = SearchParams::new(
+            &self.collection_filter,
+            false,
+            true,
+            self.ignore_diacritics,
+            true,
+            self.stem_words,
+            self.fuzzy_search,
+        );
+        let found = SearchEngine::search(&self.shown_glyph_cache, &params);
+
+        // E.g. `1F6` for all glyphs from U+1F600 to U+1F6FF.
+        let hex = self.collection_filter.trim();
+        let hex = hex
+            .strip_prefix("U+")
+            .or_else(|| hex.strip_prefix("u+"))
+            .unwrap_or(hex)
+            .to_uppercase();
+        let hex = hex.chars().all(|c| c.is_ascii_hexdigit()).then_some(hex);
+
+        self.shown_glyph_cache.retain(|chr, _| {
+            found.contains_key(chr)
+                || hex
+                    .as_ref()
+                    .is_some_and(|hex| format!("{:04X}", *chr as u32).contains(hex))
+        });
+    }
+
+    /// The "From U+____ To U+____" inputs that limit the grid to a range of
+    /// codepoints. The grid only changes once both are valid, or both empty.
+    fn render_codepoint_range(&mut self, ui: &mut egui::Ui) {
+        let (from, to) = &mut self.codepoint_range_text;
+
+        let changed = ui
+            .horizontal(|ui| {
+                let codepoint_input = |ui: &mut egui::Ui, label: &str, text: &mut String| {
+                    ui.label(label);
+
+                    let valid = text.is_empty() || parse_codepoint(text).is_some();
+                    ui.add(
+                        egui::TextEdit::singleline(text)
+                            .hint_text("____")
+                            .desired_width(64.0)
+                            .text_color_opt((!valid).then(|| ui.visuals().error_fg_color)),
+                    )
+                    .changed()
+                };
+
+                let changed = codepoint_input(ui, "From U+", from);
+                let changed = codepoint_input(ui, "To U+", to) || changed;
+
+                let clear = ui
+                    .add_enabled(
+                        !from.is_empty() || !to.is_empty(),
+                        egui::Button::new("✖").small(),
+                    )
+                    .on_hover_text("Clear Range")
+                    .clicked();
+
+                if clear {
+                    from.clear();
+                    to.clear();
+                }
+
+                changed || clear
+            })
+            .inner;
+
+        if !changed {
+            return;
+        }
+
+        let range = match (
+            from.is_empty() && to.is_empty(),
+            parse_codepoint(from),
+            parse_codepoint(to),
+        ) {
+            (true, _, _) => None,
+            (false, Some(from), Some(to)) if from <= to => Some(from..=to),
+            // Keep showing the last valid range while the user is typing.
+            _ => return,
+        };
+
+        if range != self.codepoint_range {
+            self.codepoint_range = range;
+            self.update_search_text_and_shown_glyph_cache();
+        }
+    }
+
+    /// Counts the glyphs of the fonts in every block.
+    fn update_coverage_map(&mut self) {
+        self.coverage_map.clear();
+
+        let mut codepoint = 0;
+        while codepoint <= char::MAX as u32 {
+            let Some(block) = char::from_u32(codepoint).and_then(ub::find_unicode_block) else {
+                codepoint += 1;
+                continue;
+            };
+
+            self.coverage_map.push(BlockFontCoverage {
+                block,
+                covered: self.block_glyph_count(block),
+                assigned: (block.start()..=block.end())
+                    .filter_map(char::from_u32)
+                    .filter(|chr| !chr.is_unassigned())
+                    .count(),
+            });
+            codepoint = block.end() + 1;
+        }
+    }
+
+    /// A square per block, grouped by plane, the more opaque the more of the
+    /// block the fonts cover. Clicking one shows the block.
+    fn render_coverage_map(&mut self, ui: &mut egui::Ui) {
+        if self.coverage_map.is_empty() {
+            self.update_coverage_map();
+        }
+
+        // Private use areas would dwarf everything else.
+        let (covered, assigned) = self
+            .coverage_map
+            .iter()
+            .filter(|coverage| {
+                !char::from_u32(coverage.block.start()).is_some_and(|chr| chr.is_private_use())
+            })
+            .fold((0, 0), |(covered, assigned), coverage| {
+                (covered + coverage.covered, assigned + coverage.assigned)
+            });
+        ui.label(format!(
+            "The fonts have glyphs for {covered} of {assigned} assigned characters outside the private use areas."
+        ));
+
+        let size = egui::Vec2::splat(ui.text_style_height(&egui::TextStyle::Body));
+        let fill = ui.visuals().selection.bg_fill;
+        let empty_fill = ui.visuals().faint_bg_color;
+        let mut clicked = None;
+
+        let mut planes = self
+            .coverage_map
+            .iter()
+            .map(|coverage| coverage.block.start() >> 16)
+            .collect::<Vec<_>>();
+        planes.dedup();
+
+        for plane in planes {
+            let blocks = self
+                .coverage_map
+                .iter()
+                .filter(|coverage| plane == coverage.block.start() >> 16);
+
+            ui.strong(format!("Plane {plane}: {}", plane_name(plane)));
+
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing = egui::Vec2::splat(2.0);
+
+                for coverage in blocks {
+                    let fraction = if 0 == coverage.assigned {
+                        0.0
+                    } else {
+                        coverage.covered as f32 / coverage.assigned as f32
+                    };
+
+                    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+                    ui.painter().rect_filled(
+                        rect,
+                        2.0,
+                        if 0 == coverage.covered {
+                            empty_fill
+                        } else {
+                            fill.gamma_multiply(0.2 + 0.8 * fraction.min(1.0))
+                        },
+                    );
+
+                    let response = response.on_hover_text(format!(
+                        "{}\n{} of {} ({:.0}%)",
+                        coverage.block.name(),
+                        coverage.covered,
+                        coverage.assigned,
+                        100.0 * fraction,
+                    ));
+                    if response.clicked() {
+                        clicked = Some(coverage.block);
+                    }
+                }
+            });
+        }
+
+        if let Some(block) = clicked {
+            self.show_entire_block(block);
+        }
+    }
+
+    /// Shows each unencoded glyph rendered by its id, with the id and the
+    /// glyph's name from the font, if it has one.
+    fn render_unencoded_glyphs(&mut self, ui: &mut egui::Ui) {
+        let Some(unencoded) = &mut self.unencoded_glyphs else {
+            return;
+        };
+
+        if unencoded.glyphs.is_empty() {
+            ui.label("Every glyph of this font is mapped to a character.");
+            return;
+        }
+
+        ui.label(format!("{} glyphs", unencoded.glyphs.len()));
+
+        let size = self.default_font_id.size;
+        let cell_width = 4.0 * size;
+        let row_height = 2.0 * size + 2.0 * ui.text_style_height(&egui::TextStyle::Small);
+        let columns = ((ui.available_width() / cell_width) as usize).max(1);
+        let rows = unencoded.glyphs.len().div_ceil(columns);
+
+        egui::ScrollArea::vertical().show_rows(ui, row_height, rows, |ui, rows| {
+            for row in rows {
+                ui.horizontal(|ui| {
+                    for glyph in unencoded.glyphs.iter().skip(row * columns).take(columns) {
+                        ui.allocate_ui(egui::Vec2::new(cell_width, row_height), |ui| {
+                            ui.vertical_centered(|ui| {
+                                let size_in_pixels =
+                                    (size * ui.ctx().pixels_per_point()).round() as u32;
+                                let texture = unencoded
+                                    .textures
+                                    .entry((glyph.id, size_in_pixels))
+                                    .or_insert_with(|| {
+                                        ui.ctx().load_texture(
+                                            format!("unencoded-glyph-{}", glyph.id),
+                                            unencoded.font.rasterize(
+                                                rusttype::GlyphId(glyph.id),
+                                                size_in_pixels as _,
+                                                true,
+                                            ),
+                                            Default::default(),
+                                        )
+                                    });
+                                // Rasterized white, like the crisp glyphs.
+                                ui.add(
+                                    egui::Image::new(
+                                        texture.id(),
+                                        texture.size_vec2() / ui.ctx().pixels_per_point(),
+                                    )
+                                    .tint(ui.visuals().text_color()),
+                                );
+
+                                ui.small(format!("#{}", glyph.id));
+                                ui.small(glyph.name.as_deref().unwrap_or("–"));
+                            });
+                        });
+                    }
+                });
+            }
+        });
+    }
+
+    /// Breaks the pasted text into what a reader sees as characters and
+    /// names them. Each codepoint can be selected for the inspector.
+    fn render_string_inspector(&mut self, ui: &mut egui::Ui) {
+        ui.add(
+            egui::TextEdit::multiline(&mut self.inspected_string)
+                .hint_text("Paste Text to Inspect")
+                .desired_rows(2),
+        );
+
+        ui.checkbox(&mut self.show_canonical_order, "Show Canonical Order")
+            .on_hover_ui(|ui| {
+                ui.label("List Combining Marks Sorted by Combining Class, as Normalization Does");
+            });
+
+        let inspected_string = self.inspected_string.clone();
+
+        // Positions are counted in codepoints, from 1.
+        let deceptive = inspected_string
+            .chars()
+            .enumerate()
+            .filter(|(_, chr)| is_deceptive(*chr))
+            .map(|(index, chr)| (index + 1, chr))
+            .collect::<Vec<_>>();
+
+        if !deceptive.is_empty() {
+            let error_color = ui.visuals().error_fg_color;
+
+            egui::Frame::none()
+                .fill(error_color.linear_multiply(0.1))
+                .stroke(egui::Stroke::new(1.0_f32, error_color))
+                .rounding(egui::Rounding::same(4.0))
+                .inner_margin(egui::Margin::same(6.0))
+                .show(ui, |ui| {
+                    ui.colored_label(
+                        error_color,
+                        format!(
+                            "⚠ {} Invisible or Deceptive Character{}",
+                            deceptive.len(),
+                            if 1 == deceptive.len() { "" } else { "s" }
+                        ),
+                    );
+
+                    for (position, chr) in &deceptive {
+                        ui.label(format!(
+                            "#{position}: {} {}",
+                            self.codepoint_padding.format(*chr),
+                            title_case(&self.display_name(*chr))
+                        ));
+                    }
+                });
+        }
+
+        egui::Grid::new("string_inspector")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for grapheme in string_inspector::graphemes(&inspected_string) {
+                    ui.label(egui::RichText::new(grapheme.text).size(self.default_font_id.size));
+
+                    ui.vertical(|ui| {
+                        let mut chars = grapheme.text.chars();
+                        let name = match (grapheme.sequence_name, chars.next(), chars.next()) {
+                            (Some(name), _, _) => capitalize(name),
+                            (None, Some(chr), None) => title_case(&self.display_name(chr)),
+                            // Not a known sequence; its codepoints below say what it is.
+                            _ => "Sequence".to_string(),
+                        };
+                        ui.label(name);
+
+                        ui.horizontal_wrapped(|ui| {
+                            for chr in grapheme.text.chars() {
+                                let mut codepoint =
+                                    egui::RichText::new(self.codepoint_padding.format(chr));
+                                if is_deceptive(chr) {
+                                    codepoint = codepoint.color(ui.visuals().error_fg_color);
+                                }
+
+                                if ui
+                                    .small_button(codepoint)
+                                    .on_hover_text(title_case(&self.display_name(chr)))
+                                    .clicked()
+                                {
+                                    self.selected_char = chr;
+                                }
+                            }
+                        });
+
+                        let canonical_order = string_inspector::canonical_order(grapheme.text);
+                        if self.show_canonical_order
+                            && !canonical_order.iter().copied().eq(grapheme.text.chars())
+                        {
+                            ui.horizontal_wrapped(|ui| {
+                                ui.weak("Canonical Order:")
+                                    .on_hover_text("Why Equal-Looking Strings can Compare Unequal");
+
+                                for chr in canonical_order {
+                                    if ui
+                                        .small_button(self.codepoint_padding.format(chr))
+                                        .on_hover_text(format!(
+                                            "{}\nCombining Class {}",
+                                            title_case(&self.display_name(chr)),
+                                            canonical_combining_class(chr)
+                                        ))
+                                        .clicked()
+                                    {
+                                        self.selected_char = chr;
+                                    }
+                                }
+                            });
+                        }
+                    });
+
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// The `index`th section of the inspector below the glyph's details.
+    /// Pressing its shortcut, with the `index`th of `INSPECTOR_SECTION_KEYS`,
+    /// toggles it and closes the others.
+    fn inspector_section(
+        &mut self,
+        ui: &mut egui::Ui,
+        index: usize,
+        pressed: Option<usize>,
+        name: &str,
+        add_contents: impl FnOnce(&mut Self, &mut egui::Ui),
+    ) {
+        let is_open = || {
+            egui::collapsing_header::CollapsingState::load(
+                ui.ctx(),
+                ui.make_persistent_id(egui::Id::new(name)),
+            )
+            .is_some_and(|state| state.is_open())
+        };
+        let open = pressed.map(|pressed| index == pressed && !is_open());
+
+        let response = egui::CollapsingHeader::new(name)
+            .open(open)
+            .show(ui, |ui| add_contents(self, ui));
+
+        if let Some(&key) = INSPECTOR_SECTION_KEYS.get(index) {
+            response.header_response.on_hover_text(
+                ui.ctx()
+                    .format_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, key)),
+            );
+        }
+    }
+
+    /// Shows `node` and, indented below it, what it decomposes into.
+    fn render_decomposition(&mut self, ui: &mut egui::Ui, node: &DecompositionNode) {
+        // Show combining marks on a dotted circle, as in the Unicode charts.
+        let glyph = if node.chr.is_mark_nonspacing() {
+            format!("\u{25CC}{}", node.chr)
+        } else {
+            node.chr.to_string()
+        };
+
+        if ui
+            .selectable_label(
+                node.chr == self.selected_char,
+                format!(
+                    "{glyph} {} {}",
+                    self.codepoint_padding.format(node.chr),
+                    title_case(&self.display_name(node.chr))
+                ),
+            )
+            .clicked()
+        {
+            self.selected_char = node.chr;
+        }
+
+        if !node.children.is_empty() {
+            ui.indent(("decomposition", node.chr), |ui| {
+                for child in &node.children {
+                    self.render_decomposition(ui, child);
+                }
+            });
+        }
+    }
+
+    /// Shows how the name of a Hangul syllable is spelled from its jamo or
+    /// that of an ideograph from its codepoint.
+    fn render_name_derivation(&mut self, ui: &mut egui::Ui) {
+        let chr = self.selected_char;
+
+        if let Some(jamo) = derived_name::hangul_jamo(chr) {
+            ui.horizontal_wrapped(|ui| {
+                for (i, (jamo, short_name)) in jamo.into_iter().enumerate() {
+                    if 0 != i {
+                        ui.label("+");
+                    }
+
+                    // The silent leading consonant adds nothing to the name.
+                    let short_name = if short_name.is_empty() {
+                        "–"
+                    } else {
+                        short_name
+                    };
+
+                    if ui
+                        .selectable_label(false, format!("{jamo} {short_name}"))
+                        .on_hover_text(title_case(&self.display_name(jamo)))
+                        .clicked()
+                    {
+                        self.selected_char = jamo;
+                    }
+                }
+            });
+            ui.label("Hangul Syllable + the short name of each jamo.");
+        } else if let Some(prefix) = derived_name::name_prefix(chr) {
+            ui.label(format!(
+                "{} + the codepoint, {:04X}.",
+                title_case(prefix.trim_end_matches('-')),
+                chr as u32
+            ));
+        }
+    }
+
+    /// Shows the styled forms (circled, fullwidth, mathematical, …) of the
+    /// letter or digit the selected glyph is a form of.
+    fn render_variants(&mut self, ui: &mut egui::Ui) {
+        let variants = variants::base_of(self.selected_char)
+            .map(variants::variants)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|chr| self.full_glyph_cache.contains_key(chr))
+            .collect::<Vec<_>>();
+
+        self.related_glyph_buttons(ui, variants);
+    }
 
-                            let mut unicode_hex_string_ui = String::from("U+");
-                            let mut unicode_html_string = String::from("&#");
+    /// Lists the categories the selected glyph is in. Clicking one shows it.
+    fn render_glyph_categories(&mut self, ui: &mut egui::Ui) {
+        let chr = self.selected_char;
 
-                            if 0 != unicode_hex[3] {
-                                unicode_hex_string_ui += &format!("{:02X}\u{2009}", unicode_hex[3]);
-                                unicode_html_string += &format!("{:02X}", unicode_hex[3]);
-                            }
+        if self.glyph_categories.as_ref().map(|(listed, _)| *listed) != Some(chr) {
+            let indices = self
+                .categories
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, category))| category.contains(chr))
+                .map(|(index, _)| index)
+                .collect();
+            self.glyph_categories = Some((chr, indices));
+        }
 
-                            unicode_hex[..2].iter().rev().for_each(|&uc| {
-                                if 0 != uc || !unicode_hex_string_ui.is_empty() {
-                                    unicode_hex_string_ui += &format!("{uc:02X}\u{2009}");
-                                    unicode_html_string += &format!("{uc:02X}");
-                                }
-                            });
+        let indices = self
+            .glyph_categories
+            .as_ref()
+            .map(|(_, indices)| indices.clone())
+            .unwrap_or_default();
 
-                            if ui
-                                .button(egui::RichText::new(unicode_hex_string_ui).monospace())
-                                .on_hover_ui(|ui| {
-                                    ui.label("Click to Copy Unicode as HTML");
-                                })
-                                .clicked()
-                            {
-                                ui.output_mut(|o| o.copied_text = unicode_html_string);
-                            }
+        if indices.is_empty() {
+            ui.label("In No Category");
+            return;
+        }
 
-                            ui.end_row();
+        ui.horizontal_wrapped(|ui| {
+            for index in indices {
+                if ui
+                    .selectable_label(
+                        Some(Selection::Category(index)) == self.selection,
+                        self.categories[index].0.as_str(),
+                    )
+                    .clicked()
+                {
+                    self.selection = Some(Selection::Category(index));
+                    self.update_search_text_and_shown_glyph_cache();
+                }
+            }
+        });
+    }
 
-                            // Utf8
-                            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
-                                ui.label("UTF-8");
-                            });
+    /// Finds glyphs in the current view that look like the selected one, on
+    /// request as it rasterizes every glyph in the view.
+    fn render_similar_glyphs(&mut self, ui: &mut egui::Ui) {
+        if ui
+            .button("Find Similar-Looking in View")
+            .on_hover_text(format!(
+                "Compares the Renderings of up to {} Glyphs",
+                SimilarityCache::MAX_CANDIDATES
+            ))
+            .clicked()
+        {
+            let similar = self.similarity_cache.find_similar(
+                self.crisp_glyph_cache.rasterizer(),
+                self.selected_char,
+                self.shown_glyph_cache.keys().copied(),
+                SimilarityCache::MAX_CANDIDATES,
+            );
+            self.similar_glyphs = Some((self.selected_char, similar));
+            self.similar_glyphs_shown = self.related_glyph_limit;
+        }
 
-                            let mut utf_eight = [0u8; 4];
-                            encode_unicode::Utf8Char::new(self.selected_char)
-                                .to_slice(&mut utf_eight);
+        if let Some((_, similar)) = self
+            .similar_glyphs
+            .clone()
+            .filter(|(chr, _)| *chr == self.selected_char)
+        {
+            if similar.is_empty() {
+                ui.label("Nothing Found");
+            } else {
+                self.similar_glyphs_shown =
+                    self.ranked_glyph_buttons(ui, &similar, self.similar_glyphs_shown);
+            }
+        }
+    }
 
-                            let mut utf_eight_string_ui = String::new();
-                            let mut utf_eight_string = String::new();
+    /// Lists the glyphs the fonts have that Unicode considers confusable with
+    /// the selected one. See [`similarity::confusables()`].
+    fn render_confusables(&mut self, ui: &mut egui::Ui) {
+        let chr = self.selected_char;
 
-                            if 0 != utf_eight[3] {
-                                utf_eight_string_ui += &format!("{:02X}\u{2009}", utf_eight[3]);
-                                utf_eight_string += &format!("{:02X}", utf_eight[3]);
-                            }
+        if self.confusables.as_ref().map(|(listed, _)| *listed) != Some(chr) {
+            let confusables = similarity::confusables(chr, self.full_glyph_cache.keys().copied());
+            self.confusables = Some((chr, confusables));
+        }
 
-                            utf_eight[..2].iter().rev().for_each(|&uc| {
-                                if 0 != uc || !utf_eight_string.is_empty() {
-                                    utf_eight_string_ui += &format!("{uc:02X}\u{2009}");
-                                    utf_eight_string += &format!("{uc:02X}");
-                                }
-                            });
+        let confusables = self
+            .confusables
+            .as_ref()
+            .map(|(_, confusables)| confusables.clone())
+            .unwrap_or_default();
 
-                            if ui
-                                .button(egui::RichText::new(utf_eight_string_ui).monospace())
-                                .on_hover_ui(|ui| {
-                                    ui.label("Click to Copy UTF8 Code");
-                                })
-                                .clicked()
-                            {
-                                ui.output_mut(|o| o.copied_text = utf_eight_string);
-                            }
+        if confusables.is_empty() {
+            ui.label("None Known");
+        } else {
+            self.related_glyph_buttons(ui, confusables);
+        }
+    }
 
-                            ui.end_row();
-                        });
+    /// A canvas to draw a glyph on. When a line is finished the glyphs in
+    /// view that look most like the drawing are listed.
+    fn render_drawing_search(&mut self, ui: &mut egui::Ui) {
+        let side = 10.0 * ui.text_style_height(&egui::TextStyle::Body);
+        let (response, painter) = ui.allocate_painter(egui::Vec2::splat(side), egui::Sense::drag());
+        let rect = response.rect;
 
-                    egui::Grid::new("collect")
-                        .num_columns(1)
-                        //.min_col_width(scale)
-                        //.spacing([40.0, 4.0])
-                        .striped(true)
-                        .show(ui, |ui| {
-                            ui.end_row();
+        if let Some(position) = response.interact_pointer_pos() {
+            let point = (position - rect.min) / side;
+            let point = [point.x.clamp(0.0, 1.0), point.y.clamp(0.0, 1.0)];
 
-                            ui.centered_and_justified(|ui| {
-                                let is_in_collection =
-                                    self.collection.contains(&self.selected_char);
+            if response.drag_started() || self.drawing.is_empty() {
+                self.drawing.push(Vec::new());
+            }
+            if let Some(stroke) = self.drawing.last_mut() {
+                if stroke.last() != Some(&point) {
+                    stroke.push(point);
+                }
+            }
+        }
 
-                                if ui
-                                    .add(egui::SelectableLabel::new(is_in_collection, "Collect"))
-                                    .on_hover_ui(|ui: &mut egui::Ui| {
-                                        ui.label("Add Glyp to Collection");
-                                    })
-                                    .clicked()
-                                {
-                                    if is_in_collection {
-                                        self.collection.remove(&self.selected_char);
-                                    } else {
-                                        self.collection.insert(self.selected_char);
-                                    }
-                                }
-                            });
-                            ui.end_row();
-                        });
-                },
+        if response.drag_released() {
+            self.drawing_matches = self.similarity_cache.find_like_drawing(
+                self.crisp_glyph_cache.rasterizer(),
+                &self.drawing,
+                self.shown_glyph_cache.keys().copied(),
+                SimilarityCache::MAX_CANDIDATES,
             );
-        });
+            self.drawing_matches_shown = self.related_glyph_limit;
+        }
 
-        egui::CentralPanel::default().show(ctx, |ui| {
-            egui::ScrollArea::vertical().show(ui, |ui| {
-                ui.horizontal_wrapped(|ui| {
-                    ui.spacing_mut().item_spacing = egui::Vec2::splat(2.0);
+        painter.rect_filled(rect, 4.0, ui.visuals().extreme_bg_color);
+        // As thick as the lines that are compared.
+        let stroke = egui::Stroke::new(side * 6.0 / 64.0, ui.visuals().text_color());
+        for line in &self.drawing {
+            let points = line
+                .iter()
+                .map(|&[x, y]| rect.min + side * egui::Vec2::new(x, y))
+                .collect::<Vec<_>>();
+            if let [point] = points.as_slice() {
+                painter.circle_filled(*point, stroke.width / 2.0, stroke.color);
+            } else {
+                painter.add(egui::Shape::line(points, stroke));
+            }
+        }
 
-                    //info!("ã == a is {}", focaccia::unicode_full_case_eq("a", "ã"));
+        if ui
+            .add_enabled(!self.drawing.is_empty(), egui::Button::new("Clear"))
+            .clicked()
+        {
+            self.drawing.clear();
+            self.drawing_matches.clear();
+        }
 
-                    let recently_used = self.recently_used.clone();
-                    self.shown_glyph_cache.iter().for_each(|(&chr, name)| {
-                        let button = egui::Button::new(
-                            egui::RichText::new(chr.to_string()).font(self.default_font_id.clone()),
-                        )
-                        .frame(true)
-                        .min_size(egui::Vec2::splat(self.default_font_id.size * 2.));
+        if self.drawing.is_empty() {
+            ui.label(format!(
+                "Draw a glyph above to find it among the first {} in view.",
+                SimilarityCache::MAX_CANDIDATES
+            ));
+        } else if self.drawing_matches.is_empty() {
+            ui.label("Nothing Found");
+        } else {
+            let matches = self.drawing_matches.clone();
+            self.drawing_matches_shown =
+                self.ranked_glyph_buttons(ui, &matches, self.drawing_matches_shown);
+        }
+    }
 
-                        let tooltip_ui = |ui: &mut egui::Ui| {
-                            ui.label(
-                                egui::RichText::new(chr.to_string())
-                                    .font(self.default_font_id.clone()),
-                            );
-                            ui.label(format!(
-                                "{}\nU+{:X}\n\nDouble-click to copy 📋",
-                                capitalize(name),
-                                chr as u32
-                            ));
-                        };
+    /// The first `shown` of `glyphs`, best first, as
+    /// [`Self::related_glyph_buttons()`] and, if there are more, a button to
+    /// list up to `related_glyph_limit` more. Returns how many are listed.
+    fn ranked_glyph_buttons(&mut self, ui: &mut egui::Ui, glyphs: &[char], shown: usize) -> usize {
+        self.related_glyph_buttons(ui, glyphs.iter().take(shown).copied().collect());
+
+        let remaining = glyphs.len().saturating_sub(shown);
+        if 0 < remaining
+            && ui
+                .small_button(format!("+{remaining} More"))
+                .on_hover_text(format!(
+                    "List the Next {}",
+                    remaining.min(self.related_glyph_limit)
+                ))
+                .clicked()
+        {
+            shown + self.related_glyph_limit
+        } else {
+            shown
+        }
+    }
 
-                        let hover_button = ui
-                            .add_sized(egui::Vec2::splat(self.default_font_id.size * 2.), button)
-                            .on_hover_ui(tooltip_ui);
-
-                        if hover_button.double_clicked() {
-                            // Send to clipboard.
-                            ui.output_mut(|o| o.copied_text = chr.to_string());
-
-                            /*use enigo::KeyboardControllable;
-                            let mut enigo = enigo::Enigo::new();
-                            let alt_tab = "{+ALT}{TAB}{-ALT}".to_string();
-                            enigo.key_sequence_parse(&(alt_tab.clone() + &chr.to_string() + &alt_tab))
-                            */
-                        } else if hover_button.clicked() {
-                            self.selected_char = chr;
-                            self.recently_used.push_back(chr);
-                            if self.recently_used_max_len <= recently_used.len() {
-                                self.recently_used.pop_front();
-                            }
-                        }
-                    });
-                });
-            });
+    /// A row of small buttons for glyphs related to the selected one.
+    fn related_glyph_buttons(&mut self, ui: &mut egui::Ui, glyphs: Vec<char>) {
+        // Square cells that fit the widest glyphs, so they line up.
+        let cell_size = egui::Vec2::splat(1.5 * self.related_glyph_size);
+
+        ui.horizontal_wrapped(|ui| {
+            for chr in glyphs {
+                let font_id = egui::FontId::new(
+                    self.related_glyph_size,
+                    self.family_coverage.family_for(chr),
+                );
+
+                let button = ui.add_sized(
+                    cell_size,
+                    egui::SelectableLabel::new(
+                        chr == self.selected_char,
+                        egui::RichText::new(chr.to_string()).font(font_id),
+                    ),
+                );
+
+                let button = if self.show_glyph_tooltips {
+                    button.on_hover_text(format!(
+                        "{}\n{}\n\nDouble-click to copy 📋",
+                        capitalize(&self.display_name(chr)),
+                        self.codepoint_padding.format(chr)
+                    ))
+                } else {
+                    button
+                };
+
+                if button.double_clicked() {
+                    self.copy_to_clipboard(ui.ctx(), [chr], chr.to_string());
+                } else if button.clicked() {
+                    self.selected_char = chr;
+                }
+            }
         });
+    }
 
-        if false {
-            egui::Window::new("Window").show(ctx, |ui| {
-                ui.label("Windows can be moved by dragging them.");
-                ui.label("They are automatically sized based on contents.");
-                ui.label("You can turn on resizing and scrolling if you like.");
-                ui.label("You would normally choose either panels OR windows.");
-            });
+    /// Shows the selected glyph in every font that has it, side by side on a
+    /// shared baseline, each with the font's own ascent and descent.
+    fn render_font_variations(&self, ui: &mut egui::Ui) {
+        let chr = self.selected_char;
+        let fonts = self
+            .crisp_glyph_cache
+            .rasterizer()
+            .fonts()
+            .iter()
+            .filter(|raster_font| raster_font.has_glyph(chr))
+            .collect::<Vec<_>>();
+
+        if fonts.is_empty() {
+            ui.label("No Font Has This Glyph");
+            return;
         }
-    }
-}
 
-// .auto_shrink([false;2])
-impl GlyphanaApp {
-    fn update_search_text_and_shown_glyph_cache(&mut self) {
-        // Update character cache.
-        if 2 == self.selected_category {
-            //info!("Updating cache");
-            self.search_text = self.ui_search_text.clone();
-
-            self.split_search_text = self
-                .search_text
-                .split(' ')
-                .filter_map(|s| {
-                    if s.is_empty() {
-                        None
-                    } else {
-                        Some(s.to_string())
-                    }
-                })
-                .collect();
+        if ui
+            .button("💾 Save Contact Sheet…")
+            .on_hover_text("Save the Glyph in Every Font that Has It as a PNG")
+            .clicked()
+        {
+            self.save_contact_sheet();
+        }
 
-            self.split_search_text_lower = self
-                .split_search_text
-                .iter()
-                .map(|s| s.to_lowercase())
-                .collect();
+        let column_width = ui.available_width() / fonts.len() as f32;
+        let size = (0.6 * column_width).min(48.0);
+        let label_font_id = egui::FontId::proportional(10.0);
 
-            self.selected_category = 2;
+        let (ascent, descent) = fonts.iter().fold((0.0f32, 0.0f32), |(a, d), raster_font| {
+            let v_metrics = raster_font.v_metrics(size);
+            (a.max(v_metrics.ascent), d.min(v_metrics.descent))
+        });
 
-            self.shown_glyph_cache = self
-                .full_glyph_cache
-                .clone()
-                .into_iter()
-                // Filter by search string.
-                .filter(|(chr, name)| {
-                    //let mut tmp = [0u8; 4];
-
-                    //let cmp_chr unicode_case_mapping::case_folded(chr).unwrap_or_else(|| chr as _)
-                    //let chr_str = chr.encode_utf8(&mut tmp);
-
-                    //info!("{}", chr);
-                    //let cured_chr = decancer::cure(chr_str).into_str();
-                    let chr = match self.case_sensitive {
-                        true => *chr,
-                        false => {
-                            let lower_case = unicode_case_mapping::to_lowercase(*chr);
-                            match lower_case[0] {
-                                0 => *chr,
-                                _ => char::from_u32(lower_case[0]).unwrap(),
-                            }
-                        }
-                    };
+        let (response, painter) = ui.allocate_painter(
+            egui::Vec2::new(
+                ui.available_width(),
+                ascent - descent + 3.0 * label_font_id.size,
+            ),
+            egui::Sense::hover(),
+        );
 
-                    (self.search_name
-                        && self
-                            .split_search_text_lower
-                            .iter()
-                            .any(|text| name.contains(text)))
-                        || (!self.search_name && self.search_text.contains(&chr.to_string()))
-                        || self.split_search_text_lower.iter().any(|text| {
-                            glyph_names::glyph_name(chr as _).is_some_and(|t| t.contains(text))
-                        })
-                        || self.search_text.chars().any(|c| {
-                            //cured_chr.chars().next().unwrap() == c ||
-                            unicode_skeleton::confusable([chr].into_iter(), [c].into_iter())
-                        })
-                })
-                .collect()
-        } else {
-            //info!("Updating full cache for category {}!", self.selected_category);
-            self.shown_glyph_cache = self
-                .full_glyph_cache
-                .clone()
-                .into_iter()
-                // Filter by category.
-                .filter(|(chr, _)| {
-                    match self.selected_category {
-                        0 => self.recently_used.contains(chr),
-                        1 => self.collection.contains(chr),
-                        _ => self.categories[self.selected_category - CAT_START]
-                            .1
-                            .contains(*chr),
-                    }
+        let rect = response.rect;
+        let baseline = rect.top() + ascent;
 
-                    // If no category is selected display all glyphs in the font
-                    /*||
-                    self
-                        .categories
-                        .iter()
-                        .fold(true, |none_selected, cat| none_selected && !cat.2)*/
-                })
-                .collect();
+        let visuals = ui.visuals();
+        let glyph_color = visuals.strong_text_color();
+        let stroke = visuals.widgets.noninteractive.bg_stroke;
+
+        // Shared baseline
+        painter.hline(
+            rect.x_range(),
+            baseline,
+            visuals.widgets.noninteractive.fg_stroke,
+        );
+
+        for (i, raster_font) in fonts.into_iter().enumerate() {
+            let left = rect.left() + i as f32 * column_width;
+            let center = left + 0.5 * column_width;
+            let v_metrics = raster_font.v_metrics(size);
+
+            // Ascent & descent
+            let metric_range = (left + 2.0)..=(left + column_width - 2.0);
+            painter.hline(metric_range.clone(), baseline - v_metrics.ascent, stroke);
+            painter.hline(metric_range, baseline - v_metrics.descent, stroke);
+
+            painter.text(
+                egui::Pos2::new(
+                    center,
+                    baseline - raster_font.glyph_metrics(chr, size).baseline,
+                ),
+                egui::Align2::CENTER_TOP,
+                chr,
+                egui::FontId::new(
+                    size,
+                    resolve_font_family(
+                        ui.ctx(),
+                        &egui::FontFamily::Name(raster_font.name.as_str().into()),
+                    ),
+                ),
+                glyph_color,
+            );
+
+            painter.text(
+                egui::Pos2::new(center, rect.bottom()),
+                egui::Align2::CENTER_BOTTOM,
+                textwrap::wrap(&title_case(&raster_font.name.replace('-', " ")), 8).join("\n"),
+                label_font_id.clone(),
+                visuals.weak_text_color(),
+            );
         }
     }
 
@@ -1133,6 +5396,22 @@ impl GlyphanaApp {
         let right = rect.max.x - offset;
         let bottom = rect.max.y - offset;
 
+        // Sample text is shrunk to fit between the frame's sides.
+        let sample_text_width = (!self.sample_text.is_empty()).then(|| {
+            painter
+                .layout_no_wrap(
+                    self.sample_text.clone(),
+                    egui::FontId::new(glyph_scale, self.preview_font_family()),
+                    egui::Color32::TRANSPARENT,
+                )
+                .size()
+                .x
+        });
+        let glyph_scale = match sample_text_width {
+            Some(width) if right - left < width => glyph_scale * (right - left) / width,
+            _ => glyph_scale,
+        };
+
         let font = rusttype::Font::try_from_bytes(&NOTO_SANS_FONT).unwrap();
 
         //read
@@ -1154,13 +5433,38 @@ impl GlyphanaApp {
             .color
             .linear_multiply(stroke.color.r() as f32 / 255.0);
 
-        painter.text(
-            egui::Pos2::new(center.x, top),
-            egui::Align2::CENTER_TOP,
-            self.selected_char,
-            egui::FontId::new(glyph_scale, egui::FontFamily::Name(NOTO_SANS.into())),
-            glyph_color,
-        );
+        // Crisp textures are only made from the glyph font family.
+        let crisp_texture =
+            if self.crisp_glyphs && !self.preview_monospace && sample_text_width.is_none() {
+                self.crisp_glyph_cache
+                    .texture(ui.ctx(), self.selected_char, glyph_scale)
+            } else {
+                None
+            };
+
+        match crisp_texture {
+            Some(texture) => paint_crisp_glyph(
+                &painter,
+                &texture,
+                egui::Pos2::new(center.x, top),
+                egui::Align2::CENTER_TOP,
+                glyph_color,
+            ),
+            None => {
+                let text = match sample_text_width {
+                    Some(_) => self.sample_text.clone(),
+                    None => self.selected_char.to_string(),
+                };
+
+                painter.text(
+                    egui::Pos2::new(center.x, top),
+                    egui::Align2::CENTER_TOP,
+                    text,
+                    egui::FontId::new(glyph_scale, self.preview_font_family()),
+                    glyph_color,
+                );
+            }
+        }
 
         // Ascent
         painter.line_segment(
@@ -1189,33 +5493,339 @@ impl GlyphanaApp {
             stroke,
         );
 
+        let metrics = if self.show_glyph_bounds && sample_text_width.is_none() {
+            self.preview_rasterizer()
+                .glyph_metrics(self.selected_char, glyph_scale)
+        } else {
+            None
+        };
+
+        if let Some(metrics) = metrics {
+            let bounds_stroke = egui::Stroke::new(stroke.width, visuals.selection.bg_fill);
+
+            // Mirrors how `painter.text()` centers the glyph's advance.
+            let cell_min = egui::Pos2::new(center.x - 0.5 * metrics.advance, top);
+            let origin = cell_min + egui::Vec2::new(0.0, metrics.baseline);
+
+            if let Some(bounds) = metrics.bounds {
+                painter.rect_stroke(bounds.translate(cell_min.to_vec2()), 0.0, bounds_stroke);
+            }
+
+            // Advance
+            painter.line_segment(
+                [origin, origin + egui::Vec2::new(metrics.advance, 0.0)],
+                bounds_stroke,
+            );
+            painter.line_segment(
+                [
+                    origin + egui::Vec2::new(metrics.advance, -offset),
+                    origin + egui::Vec2::new(metrics.advance, offset),
+                ],
+                bounds_stroke,
+            );
+
+            // Origin
+            painter.circle_filled(origin, 3.0, visuals.selection.bg_fill);
+        }
+
         ui.expand_to_include_rect(painter.clip_rect());
     }
 }
 
-fn available_characters(ctx: &egui::Context, family: egui::FontFamily) -> BTreeMap<char, String> {
+/// Returns `family` if egui knows it, [`egui::FontFamily::Proportional`]
+/// otherwise. egui panics when asked to render with an unknown family.
+fn resolve_font_family(ctx: &egui::Context, family: &egui::FontFamily) -> egui::FontFamily {
+    if ctx.fonts(|f| f.families().contains(family)) {
+        return family.clone();
+    }
+
+    // Called every frame, so only the first time is reported.
+    let unreported = ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<HashSet<egui::FontFamily>>(egui::Id::new(
+            "unregistered_font_families",
+        ))
+        .insert(family.clone())
+    });
+    if unreported {
+        log::warn!("Font family {family} is not registered, falling back to proportional");
+    }
+
+    egui::FontFamily::Proportional
+}
+
+/// The characters the fonts of `family` have glyphs for, with their names.
+/// Whitespace and ASCII control characters are left out unless
+/// `whitespace_and_control` is set.
+fn available_characters(
+    ctx: &egui::Context,
+    family: egui::FontFamily,
+    whitespace_and_control: bool,
+    name: impl Fn(char) -> String,
+) -> BTreeMap<char, String> {
     ctx.fonts(|f| {
         f.lock()
             .fonts
             .font(&egui::FontId::new(10.0, family)) // size is arbitrary for getting the characters
             .characters()
             .iter()
-            .filter(|chr| !chr.is_whitespace() && !chr.is_ascii_control())
-            .map(|&chr| (chr, char_name(chr)))
+            .filter(|chr| {
+                whitespace_and_control || (!chr.is_whitespace() && !chr.is_ascii_control())
+            })
+            .map(|&chr| (chr, name(chr)))
             .collect()
     })
 }
 
+/// The name the Unicode standard gives to `plane`.
+fn plane_name(plane: u32) -> &'static str {
+    match plane {
+        0 => "Basic Multilingual Plane",
+        1 => "Supplementary Multilingual Plane",
+        2 => "Supplementary Ideographic Plane",
+        3 => "Tertiary Ideographic Plane",
+        14 => "Supplementary Special-purpose Plane",
+        15 | 16 => "Supplementary Private Use Area",
+        _ => "Unassigned",
+    }
+}
+
+/// Reads the font file at `path`; `None` if it can not be read or is not a
+/// font.
+fn read_font(path: &std::path::Path) -> Option<Vec<u8>> {
+    let data = std::fs::read(path)
+        .map_err(|error| log::error!("Could not read font {path:?}: {error}"))
+        .ok()?;
+
+    match ttf_parser::Face::parse(&data, 0) {
+        Ok(_) => Some(data),
+        Err(error) => {
+            log::error!("Could not load font {path:?}: {error}");
+            None
+        }
+    }
+}
+
+/// Like [`egui::Ui::toggle_value()`] but hard to miss when on: filled and
+/// outlined in the selection color.
+fn search_toggle(ui: &mut egui::Ui, value: &mut bool, label: String) -> egui::Response {
+    let selection = ui.visuals().selection;
+
+    let button = if *value {
+        egui::Button::new(egui::RichText::new(label).color(selection.stroke.color))
+            .fill(selection.bg_fill)
+            .stroke(selection.stroke)
+    } else {
+        egui::Button::new(label)
+    };
+
+    let mut response = ui.add(button);
+
+    if response.clicked() {
+        *value = !*value;
+        response.mark_changed();
+    }
+
+    response
+}
+
+/// Parses a hex codepoint with or without a leading `U+`.
+fn parse_codepoint(text: &str) -> Option<char> {
+    let text = text.trim();
+    let hex = text
+        .strip_prefix("U+")
+        .or_else(|| text.strip_prefix("u+"))
+        .unwrap_or(text);
+
+    char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+}
+
+/// The name of the Unicode general category of `chr`.
+fn general_category_name(chr: char) -> &'static str {
+    use finl_unicode::categories::MinorCategory;
+
+    match chr.get_minor_category() {
+        MinorCategory::Lu => "Uppercase Letter",
+        MinorCategory::Ll => "Lowercase Letter",
+        MinorCategory::Lt => "Titlecase Letter",
+        MinorCategory::Lm => "Modifier Letter",
+        MinorCategory::Lo => "Other Letter",
+        MinorCategory::Mn => "Nonspacing Mark",
+        MinorCategory::Mc => "Spacing Mark",
+        MinorCategory::Me => "Enclosing Mark",
+        MinorCategory::Nd => "Decimal Number",
+        MinorCategory::Nl => "Letter Number",
+        MinorCategory::No => "Other Number",
+        MinorCategory::Pc => "Connector Punctuation",
+        MinorCategory::Pd => "Dash Punctuation",
+        MinorCategory::Ps => "Open Punctuation",
+        MinorCategory::Pe => "Close Punctuation",
+        MinorCategory::Pi => "Initial Punctuation",
+        MinorCategory::Pf => "Final Punctuation",
+        MinorCategory::Po => "Other Punctuation",
+        MinorCategory::Sm => "Math Symbol",
+        MinorCategory::Sk => "Modifier Symbol",
+        MinorCategory::Sc => "Currency Symbol",
+        MinorCategory::So => "Other Symbol",
+        MinorCategory::Zs => "Space Separator",
+        MinorCategory::Zl => "Line Separator",
+        MinorCategory::Zp => "Paragraph Separator",
+        MinorCategory::Cc => "Control",
+        MinorCategory::Cf => "Format",
+        MinorCategory::Co => "Private Use",
+        MinorCategory::Cn => "Unassigned",
+    }
+}
+
+/// Whether `chr` has the Unicode Emoji property.
+fn is_emoji(chr: char) -> bool {
+    chr.is_emoji_char()
+}
+
+/// The name of `chr` or, if it has none, its codepoint, e.g. `U+2B1C`.
 fn char_name(chr: char) -> String {
+    known_char_name(chr).unwrap_or_else(|| CodepointPadding::default().format(chr))
+}
+
+/// The name of `chr`, if it has one.
+fn known_char_name(chr: char) -> Option<String> {
     special_char_name(chr)
         .map(|s| s.to_owned())
+        // Newer ideographs are missing from the name tables.
+        .or_else(|| derived_name::derived_name(chr).map(|name| name.to_lowercase()))
         .or_else(|| unicode_names2::name(chr).map(|name| name.to_string().to_lowercase()))
-        .unwrap_or_else(|| "unknown".to_owned())
+        .or_else(|| {
+            glyph_names::glyph_name(chr as _)
+                .filter(|name| !is_codepoint_glyph_name(name))
+                .map(|name| name.into_owned())
+        })
+}
+
+/// Returns `true` for Adobe glyph names that just spell out the codepoint,
+/// `uniXXXX` or `uXXXX` to `uXXXXXX`. These are noise as a name.
+fn is_codepoint_glyph_name(name: &str) -> bool {
+    name.strip_prefix("uni")
+        .filter(|hex| 4 == hex.len())
+        .or_else(|| {
+            name.strip_prefix('u')
+                .filter(|hex| (4..=6).contains(&hex.len()))
+        })
+        .is_some_and(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Whether `chr` is invisible or changes how text around it is shown, which
+/// can be used to make e.g. identifiers look like something else: bidi
+/// overrides, zero-width and other format characters, tags, fillers and
+/// any whitespace but the usual.
+fn is_deceptive(chr: char) -> bool {
+    !matches!(chr, ' ' | '\t' | '\n' | '\r')
+        && (chr.is_format()
+            || invisible_char_abbreviation(chr).is_some()
+            || matches!(
+                chr,
+                // Combining grapheme joiner, Hangul fillers, braille blank.
+                '\u{034F}' | '\u{115F}' | '\u{1160}' | '\u{3164}' | '\u{FFA0}' | '\u{2800}'
+            ))
+}
+
+/// The abbreviation for a character that renders as nothing, e.g. `ZWJ` for
+/// zero width joiner.
+fn invisible_char_abbreviation(chr: char) -> Option<&'static str> {
+    match chr {
+        '\u{0000}'..='\u{001F}' => Some(C0_CONTROLS[chr as usize].0),
+        ' ' => Some("SP"),
+        '\u{007F}' => Some("DEL"),
+        '\u{0085}' => Some("NEL"),
+        '\u{00A0}' => Some("NBSP"),
+        '\u{00AD}' => Some("SHY"),
+        '\u{034F}' => Some("CGJ"),
+        '\u{061C}' => Some("ALM"),
+        '\u{180E}' => Some("MVS"),
+        '\u{200B}' => Some("ZWSP"),
+        '\u{200C}' => Some("ZWNJ"),
+        '\u{200D}' => Some("ZWJ"),
+        '\u{200E}' => Some("LRM"),
+        '\u{200F}' => Some("RLM"),
+        '\u{202A}' => Some("LRE"),
+        '\u{202B}' => Some("RLE"),
+        '\u{202C}' => Some("PDF"),
+        '\u{202D}' => Some("LRO"),
+        '\u{202E}' => Some("RLO"),
+        '\u{2060}' => Some("WJ"),
+        '\u{2061}' => Some("FA"),
+        '\u{2062}' => Some("IT"),
+        '\u{2063}' => Some("IS"),
+        '\u{2064}' => Some("IP"),
+        '\u{2066}' => Some("LRI"),
+        '\u{2067}' => Some("RLI"),
+        '\u{2068}' => Some("FSI"),
+        '\u{2069}' => Some("PDI"),
+        '\u{FE0E}' => Some("VS15"),
+        '\u{FE0F}' => Some("VS16"),
+        '\u{FEFF}' => Some("BOM"),
+        '\u{1680}' => Some("OSM"),
+        '\u{2000}' => Some("NQSP"),
+        '\u{2001}' => Some("MQSP"),
+        '\u{2002}' => Some("ENSP"),
+        '\u{2003}' => Some("EMSP"),
+        '\u{2004}' => Some("3/MSP"),
+        '\u{2005}' => Some("4/MSP"),
+        '\u{2006}' => Some("6/MSP"),
+        '\u{2007}' => Some("FSP"),
+        '\u{2008}' => Some("PSP"),
+        '\u{2009}' => Some("THSP"),
+        '\u{200A}' => Some("HSP"),
+        '\u{2028}' => Some("LSEP"),
+        '\u{2029}' => Some("PSEP"),
+        '\u{202F}' => Some("NNBSP"),
+        '\u{205F}' => Some("MMSP"),
+        '\u{3000}' => Some("IDSP"),
+        _ => None,
+    }
 }
 
+/// The abbreviation and name alias of each C0 control character. Control
+/// characters have no Unicode name of their own.
+const C0_CONTROLS: [(&str, &str); 32] = [
+    ("NUL", "null"),
+    ("SOH", "start of heading"),
+    ("STX", "start of text"),
+    ("ETX", "end of text"),
+    ("EOT", "end of transmission"),
+    ("ENQ", "enquiry"),
+    ("ACK", "acknowledge"),
+    ("BEL", "alert"),
+    ("BS", "backspace"),
+    ("TAB", "character tabulation"),
+    ("LF", "line feed"),
+    ("VT", "line tabulation"),
+    ("FF", "form feed"),
+    ("CR", "carriage return"),
+    ("SO", "shift out"),
+    ("SI", "shift in"),
+    ("DLE", "data link escape"),
+    ("DC1", "device control one"),
+    ("DC2", "device control two"),
+    ("DC3", "device control three"),
+    ("DC4", "device control four"),
+    ("NAK", "negative acknowledge"),
+    ("SYN", "synchronous idle"),
+    ("ETB", "end of transmission block"),
+    ("CAN", "cancel"),
+    ("EM", "end of medium"),
+    ("SUB", "substitute"),
+    ("ESC", "escape"),
+    ("FS", "information separator four"),
+    ("GS", "information separator three"),
+    ("RS", "information separator two"),
+    ("US", "information separator one"),
+];
+
 fn special_char_name(chr: char) -> Option<&'static str> {
     #[allow(clippy::match_same_arms)] // many "flag"
     match chr {
+        '\u{0000}'..='\u{001F}' => Some(C0_CONTROLS[chr as usize].1),
+        '\u{007F}' => Some("delete"),
+
         // Special private-use-area extensions found in `emoji-icon-font.ttf`:
         // Private use area extensions:
         '\u{FE4E5}' => Some("flag japan"),
@@ -1382,11 +5992,16 @@ fn capitalize(s: &str) -> String {
     }
 }
 
-/// Capitalizes the first character of every word.
+/// Capitalizes the first character of every word. Codepoints, e.g.
+/// `U+2B1C`, are kept upper case.
 fn title_case(s: &str) -> String {
     s.to_lowercase()
         .split_whitespace()
         .map(|s| {
+            if s.starts_with("u+") {
+                return s.to_uppercase();
+            }
+
             let mut c = s.chars();
             match c.next() {
                 None => String::new(),
@@ -1396,3 +6011,82 @@ fn title_case(s: &str) -> String {
         .collect::<Vec<String>>()
         .join(" ")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(app: &GlyphanaApp, name: &str) -> Vec<char> {
+        app.categories
+            .iter()
+            .find(|(category_name, _)| name == category_name)
+            .map(|(_, category)| category.characters())
+            .unwrap()
+    }
+
+    #[test]
+    fn categories_are_in_codepoint_order() {
+        let app = GlyphanaApp::default();
+
+        for name in ["Parentheses", ub::ARROWS.name()] {
+            let characters = category(&app, name);
+            assert!(!characters.is_empty(), "{name}");
+            // Strictly, so without duplicates.
+            assert!(
+                characters.windows(2).all(|pair| pair[0] < pair[1]),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn codepoint_glyph_names_are_not_names() {
+        assert!(is_codepoint_glyph_name("uni2B1C"));
+        assert!(is_codepoint_glyph_name("u1F600"));
+        assert!(!is_codepoint_glyph_name("uniform"));
+        assert!(!is_codepoint_glyph_name("eacute"));
+
+        // Unassigned, so only an Adobe glyph name spelling out its codepoint.
+        assert_eq!("U+0378", char_name('\u{0378}'));
+        assert_eq!("U+0378", title_case(&char_name('\u{0378}')));
+    }
+
+    #[test]
+    fn ui_icons_are_defined_once() {
+        let distinct = UI_ICONS.iter().map(|(chr, _)| chr).collect::<BTreeSet<_>>();
+        assert_eq!(UI_ICONS.len(), distinct.len());
+    }
+
+    #[test]
+    fn category_order_round_trips_with_character_sets() {
+        // Character sets are loaded from their files on every start.
+        let load_character_set = |app: &mut GlyphanaApp| {
+            app.categories.push((
+                "APL".to_string(),
+                UnicodeCategory::Collection(UnicodeCollection(HashSet::from_iter(['⍴', '⍳']))),
+            ));
+            app.character_set_count = 1;
+        };
+        let order = |app: &GlyphanaApp| {
+            app.unpinned_category_order()
+                .into_iter()
+                .map(|index| app.categories[index].0.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut app = GlyphanaApp::default();
+        load_character_set(&mut app);
+        let default_order = order(&app);
+        app.move_category(app.categories.len() - 1, true);
+        app.move_category(0, false);
+        let moved_order = order(&app);
+        assert_ne!(default_order, moved_order);
+
+        let mut restored =
+            serde_json::from_str::<GlyphanaApp>(&serde_json::to_string(&app).unwrap()).unwrap();
+        load_character_set(&mut restored);
+
+        assert_eq!(moved_order, order(&restored));
+        assert_eq!(vec!['⍳', '⍴'], category(&restored, "APL"));
+    }
+}
@@ -0,0 +1,139 @@
+use ahash::AHashSet as HashSet;
+use serde::Deserialize;
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+/// A named set of characters, loaded from a JSON file like this:
+///
+/// ```json
+/// {
+///     "name": "APL Symbols",
+///     "characters": ["⍴", "U+2373", "U+2336..U+237A"]
+/// }
+/// ```
+///
+/// Each entry of `characters` is a character, a codepoint or an inclusive
+/// range of either, lowest first.
+pub struct CharacterSet {
+    pub name: String,
+    pub characters: HashSet<char>,
+}
+
+#[derive(Deserialize)]
+struct CharacterSetFile {
+    name: String,
+    characters: Vec<String>,
+}
+
+impl CharacterSet {
+    /// Where the user puts character set files.
+    pub fn directory() -> Option<PathBuf> {
+        eframe::storage_dir("Glyphana").map(|dir| dir.join("character-sets"))
+    }
+
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let file: CharacterSetFile = serde_json::from_slice(&std::fs::read(path)?)?;
+
+        let characters = file
+            .characters
+            .iter()
+            .map(|entry| {
+                parse_entry(entry).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("“{entry}” is not a character, codepoint or range"),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .filter_map(char::from_u32)
+            .collect();
+
+        Ok(Self {
+            name: file.name,
+            characters,
+        })
+    }
+
+    /// Opens every `.json` file in `directory`, in file name order.
+    ///
+    /// Returns the sets and, for each file that could not be opened, its
+    /// path and why. A missing `directory` is not an error.
+    pub fn open_all(directory: &Path) -> (Vec<Self>, Vec<(PathBuf, std::io::Error)>) {
+        let mut paths = match std::fs::read_dir(directory) {
+            Ok(entries) => entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|extension| "json" == extension)
+                })
+                .collect::<Vec<_>>(),
+            Err(error) if std::io::ErrorKind::NotFound == error.kind() => Vec::new(),
+            Err(error) => return (Vec::new(), vec![(directory.to_path_buf(), error)]),
+        };
+        paths.sort();
+
+        let mut sets = Vec::new();
+        let mut errors = Vec::new();
+
+        for path in paths {
+            match Self::open(&path) {
+                Ok(set) => sets.push(set),
+                Err(error) => errors.push((path, error)),
+            }
+        }
+
+        (sets, errors)
+    }
+}
+
+/// Parses `"⍴"`, `"U+2373"` or a range of these like `"U+2336..U+237A"`.
+/// Codepoints past `U+10FFFF` and ranges running backwards are invalid.
+fn parse_entry(entry: &str) -> Option<RangeInclusive<u32>> {
+    let codepoint = |text: &str| {
+        let text = text.trim();
+
+        match text.strip_prefix("U+").or_else(|| text.strip_prefix("u+")) {
+            Some(hex) => u32::from_str_radix(hex, 16)
+                .ok()
+                .filter(|&codepoint| codepoint <= char::MAX as u32),
+            None => {
+                let mut chars = text.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(chr), None) => Some(chr as u32),
+                    _ => None,
+                }
+            }
+        }
+    };
+
+    match entry.split_once("..") {
+        Some((start, end)) => {
+            let (start, end) = (codepoint(start)?, codepoint(end)?);
+            (start <= end).then_some(start..=end)
+        }
+        None => {
+            let codepoint = codepoint(entry)?;
+            Some(codepoint..=codepoint)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries() {
+        assert_eq!(Some(0x2374..=0x2374), parse_entry("⍴"));
+        assert_eq!(Some(0x2336..=0x237A), parse_entry("U+2336..U+237A"));
+        assert_eq!(Some(0x10FFFF..=0x10FFFF), parse_entry("U+10FFFF"));
+        assert_eq!(None, parse_entry("U+110000"));
+        assert_eq!(None, parse_entry("U+0..U+FFFFFFFF"));
+        assert_eq!(None, parse_entry("U+237A..U+2336"));
+    }
+}
@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+
+/// The ways a glyph can be copied to the clipboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyFormat {
+    /// Just the glyph.
+    Glyph,
+    /// The glyph followed by its name, e.g. `💩 PILE OF POO`.
+    GlyphAndName,
+    /// As above with the codepoint in between, e.g. `💩 U+1F4A9 PILE OF POO`.
+    GlyphCodepointAndName,
+    /// A Markdown table row, e.g. `| 💩 | U+1F4A9 | PILE OF POO |`.
+    MarkdownTableRow,
+    /// A Python string literal, e.g. `'\U0001F4A9'`.
+    PythonString,
+    /// A JSON string with UTF-16 escapes, e.g. `"\ud83d\udca9"`.
+    JsonString,
+}
+
+impl CopyFormat {
+    pub const ALL: [CopyFormat; 6] = [
+        CopyFormat::Glyph,
+        CopyFormat::GlyphAndName,
+        CopyFormat::GlyphCodepointAndName,
+        CopyFormat::MarkdownTableRow,
+        CopyFormat::PythonString,
+        CopyFormat::JsonString,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CopyFormat::Glyph => "Glyph",
+            CopyFormat::GlyphAndName => "Glyph & Name",
+            CopyFormat::GlyphCodepointAndName => "Glyph, Codepoint & Name",
+            CopyFormat::MarkdownTableRow => "Markdown Table Row",
+            CopyFormat::PythonString => "Python String",
+            CopyFormat::JsonString => "JSON String",
+        }
+    }
+
+    /// Formats `chr`; `name` is expected in any case and is emitted upper
+    /// case, as in the Unicode standard.
+    pub fn format(self, chr: char, name: &str, padding: CodepointPadding) -> String {
+        match self {
+            CopyFormat::Glyph => chr.to_string(),
+            CopyFormat::GlyphAndName => format!("{chr} {}", name.to_uppercase()),
+            CopyFormat::GlyphCodepointAndName => {
+                format!("{chr} {} {}", padding.format(chr), name.to_uppercase())
+            }
+            CopyFormat::MarkdownTableRow => {
+                // A pipe would end the cell.
+                let glyph = match chr {
+                    '|' => "\\|".to_string(),
+                    _ => chr.to_string(),
+                };
+
+                format!(
+                    "| {glyph} | {} | {} |",
+                    padding.format(chr),
+                    name.to_uppercase()
+                )
+            }
+            CopyFormat::PythonString => {
+                let escaped = match chr {
+                    '\'' | '\\' => format!("\\{chr}"),
+                    ' '..='~' => chr.to_string(),
+                    '\0'..='\u{ff}' => format!("\\x{:02X}", chr as u32),
+                    '\u{100}'..='\u{ffff}' => format!("\\u{:04X}", chr as u32),
+                    // Astral characters need all eight digits.
+                    _ => format!("\\U{:08X}", chr as u32),
+                };
+
+                format!("'{escaped}'")
+            }
+            CopyFormat::JsonString => {
+                let escaped = match chr {
+                    '"' | '\\' => format!("\\{chr}"),
+                    ' '..='~' => chr.to_string(),
+                    // Astral characters become a surrogate pair.
+                    _ => chr
+                        .encode_utf16(&mut [0; 2])
+                        .iter()
+                        .map(|unit| format!("\\u{unit:04x}"))
+                        .collect(),
+                };
+
+                format!("\"{escaped}\"")
+            }
+        }
+    }
+}
+
+/// A Markdown table with a header and a [`CopyFormat::MarkdownTableRow`]
+/// for each of `glyphs`.
+pub fn markdown_table(
+    glyphs: impl IntoIterator<Item = (char, String)>,
+    padding: CodepointPadding,
+) -> String {
+    glyphs.into_iter().fold(
+        "| Glyph | Codepoint | Name |\n| --- | --- | --- |\n".to_string(),
+        |table, (chr, name)| {
+            table + &CopyFormat::MarkdownTableRow.format(chr, &name, padding) + "\n"
+        },
+    )
+}
+
+/// How many hex digits codepoints are padded to with zeros.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CodepointPadding {
+    /// E.g. `U+E4`.
+    None,
+    /// E.g. `U+00E4` and `U+1F600`, as in the Unicode standard.
+    #[default]
+    Four,
+    /// E.g. `U+0000E4` and `U+01F600`, all of the same width.
+    Six,
+}
+
+impl CodepointPadding {
+    pub const ALL: [CodepointPadding; 3] = [
+        CodepointPadding::None,
+        CodepointPadding::Four,
+        CodepointPadding::Six,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CodepointPadding::None => "None",
+            CodepointPadding::Four => "4 Digits",
+            CodepointPadding::Six => "6 Digits",
+        }
+    }
+
+    /// `chr` as e.g. `U+1F600`.
+    pub fn format(self, chr: char) -> String {
+        let width = match self {
+            CodepointPadding::None => 0,
+            CodepointPadding::Four => 4,
+            CodepointPadding::Six => 6,
+        };
+
+        format!("U+{:0width$X}", chr as u32)
+    }
+}
+
+/// What goes between glyphs when several are copied at once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Separator {
+    /// The glyphs are concatenated.
+    #[default]
+    None,
+    Space,
+    /// One glyph per line.
+    Newline,
+    /// A comma and a space.
+    Comma,
+}
+
+impl Separator {
+    pub const ALL: [Separator; 4] = [
+        Separator::None,
+        Separator::Space,
+        Separator::Newline,
+        Separator::Comma,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Separator::None => "None",
+            Separator::Space => "Space",
+            Separator::Newline => "Newline",
+            Separator::Comma => "Comma",
+        }
+    }
+
+    pub fn join(self, glyphs: impl IntoIterator<Item = char>) -> String {
+        let separator = match self {
+            Separator::None => "",
+            Separator::Space => " ",
+            Separator::Newline => "\n",
+            Separator::Comma => ", ",
+        };
+
+        glyphs
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn python_string_escapes_astral_characters_with_eight_digits() {
+        assert_eq!(
+            "'\\U0001F600'",
+            CopyFormat::PythonString.format('😀', "grinning face", CodepointPadding::Four)
+        );
+    }
+
+    #[test]
+    fn json_string_escapes_astral_characters_as_surrogate_pair() {
+        assert_eq!(
+            "\"\\ud83d\\ude00\"",
+            CopyFormat::JsonString.format('😀', "grinning face", CodepointPadding::Four)
+        );
+    }
+}
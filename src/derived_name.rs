@@ -0,0 +1,128 @@
+// Names the Unicode standard derives from the codepoint instead of listing
+// them, see section 4.8 of the core specification, rules NR1 and NR2.
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const S_COUNT: u32 = 19 * V_COUNT * T_COUNT;
+
+// The short names of the leading consonant, vowel and trailing consonant
+// jamo, from `Jamo.txt`.
+const L_NAMES: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const V_NAMES: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+const T_NAMES: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+// Ranges whose names are a prefix followed by the codepoint (Unicode 15.1).
+const PREFIXED_RANGES: [(u32, u32, &str); 17] = [
+    (0x3400, 0x4DBF, "CJK UNIFIED IDEOGRAPH-"),
+    (0x4E00, 0x9FFF, "CJK UNIFIED IDEOGRAPH-"),
+    (0xF900, 0xFA6D, "CJK COMPATIBILITY IDEOGRAPH-"),
+    (0xFA70, 0xFAD9, "CJK COMPATIBILITY IDEOGRAPH-"),
+    (0x17000, 0x187F7, "TANGUT IDEOGRAPH-"),
+    (0x18B00, 0x18CD5, "KHITAN SMALL SCRIPT CHARACTER-"),
+    (0x18D00, 0x18D08, "TANGUT IDEOGRAPH-"),
+    (0x1B170, 0x1B2FB, "NUSHU CHARACTER-"),
+    (0x20000, 0x2A6DF, "CJK UNIFIED IDEOGRAPH-"),
+    (0x2A700, 0x2B739, "CJK UNIFIED IDEOGRAPH-"),
+    (0x2B740, 0x2B81D, "CJK UNIFIED IDEOGRAPH-"),
+    (0x2B820, 0x2CEA1, "CJK UNIFIED IDEOGRAPH-"),
+    (0x2CEB0, 0x2EBE0, "CJK UNIFIED IDEOGRAPH-"),
+    (0x2EBF0, 0x2EE5D, "CJK UNIFIED IDEOGRAPH-"),
+    (0x2F800, 0x2FA1D, "CJK COMPATIBILITY IDEOGRAPH-"),
+    (0x30000, 0x3134A, "CJK UNIFIED IDEOGRAPH-"),
+    (0x31350, 0x323AF, "CJK UNIFIED IDEOGRAPH-"),
+];
+
+/// The derived name of a Hangul syllable or ideograph, e.g. `HANGUL
+/// SYLLABLE GA` or `CJK UNIFIED IDEOGRAPH-4E00`.
+pub fn derived_name(chr: char) -> Option<String> {
+    if let Some(jamo) = hangul_jamo(chr) {
+        return Some(
+            jamo.iter()
+                .fold("HANGUL SYLLABLE ".to_string(), |name, (_, short_name)| {
+                    name + short_name
+                }),
+        );
+    }
+
+    name_prefix(chr).map(|prefix| format!("{prefix}{:04X}", chr as u32))
+}
+
+/// The prefix of a derived name that is followed by the codepoint, e.g.
+/// `CJK UNIFIED IDEOGRAPH-` for `一`.
+pub fn name_prefix(chr: char) -> Option<&'static str> {
+    let codepoint = chr as u32;
+    PREFIXED_RANGES
+        .iter()
+        .find(|(first, last, _)| (*first..=*last).contains(&codepoint))
+        .map(|(_, _, prefix)| *prefix)
+}
+
+/// The jamo a Hangul syllable is made of, leading consonant, vowel and,
+/// if any, trailing consonant, each with the short name it contributes to
+/// the syllable's name.
+pub fn hangul_jamo(chr: char) -> Option<Vec<(char, &'static str)>> {
+    let index = (chr as u32)
+        .checked_sub(S_BASE)
+        .filter(|&index| index < S_COUNT)?;
+
+    let l_index = index / (V_COUNT * T_COUNT);
+    let v_index = (index % (V_COUNT * T_COUNT)) / T_COUNT;
+    let t_index = index % T_COUNT;
+
+    let mut jamo = vec![
+        (char::from_u32(L_BASE + l_index)?, L_NAMES[l_index as usize]),
+        (char::from_u32(V_BASE + v_index)?, V_NAMES[v_index as usize]),
+    ];
+    if 0 != t_index {
+        jamo.push((char::from_u32(T_BASE + t_index)?, T_NAMES[t_index as usize]));
+    }
+
+    Some(jamo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ideograph_names() {
+        assert_eq!(
+            Some("CJK UNIFIED IDEOGRAPH-4E00"),
+            derived_name('\u{4E00}').as_deref()
+        );
+        assert_eq!(
+            Some("CJK UNIFIED IDEOGRAPH-20000"),
+            derived_name('\u{20000}').as_deref()
+        );
+        assert_eq!(None, derived_name('A'));
+    }
+
+    #[test]
+    fn hangul_syllable_names() {
+        assert_eq!(
+            Some("HANGUL SYLLABLE GA"),
+            derived_name('\u{AC00}').as_deref()
+        );
+        assert_eq!(
+            Some("HANGUL SYLLABLE HIH"),
+            derived_name('\u{D7A3}').as_deref()
+        );
+        assert_eq!(
+            Some(vec![('\u{1100}', "G"), ('\u{1161}', "A")]),
+            hangul_jamo('\u{AC00}')
+        );
+    }
+}
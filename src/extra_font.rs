@@ -0,0 +1,46 @@
+use std::path::{Path, PathBuf};
+
+/// A font file the user put next to Glyphana's saved state to cover scripts
+/// the bundled fonts lack, e.g. `NotoSansDevanagari-Regular.ttf`.
+pub struct ExtraFont {
+    /// The file name, which is also what the font is registered as.
+    pub name: String,
+    pub path: PathBuf,
+}
+
+impl ExtraFont {
+    /// Where the user puts extra font files.
+    pub fn directory() -> Option<PathBuf> {
+        eframe::storage_dir("Glyphana").map(|dir| dir.join("fonts"))
+    }
+
+    /// The font files in `directory`, in file name order. A missing
+    /// `directory` has none.
+    pub fn list(directory: &Path) -> std::io::Result<Vec<Self>> {
+        let entries = match std::fs::read_dir(directory) {
+            Ok(entries) => entries,
+            Err(error) if std::io::ErrorKind::NotFound == error.kind() => return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+
+        let mut fonts = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                path.extension().is_some_and(|extension| {
+                    ["ttf", "otf"]
+                        .iter()
+                        .any(|font| extension.eq_ignore_ascii_case(font))
+                })
+            })
+            .filter_map(|path| {
+                Some(Self {
+                    name: path.file_name()?.to_string_lossy().into_owned(),
+                    path,
+                })
+            })
+            .collect::<Vec<_>>();
+        fonts.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(fonts)
+    }
+}
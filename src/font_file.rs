@@ -0,0 +1,131 @@
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use std::{fmt, path::PathBuf};
+
+/// A face in a font file. Plain font files contain exactly one, TrueType
+/// and OpenType Collections (`.ttc`, `.otc`) several.
+pub struct FontFace {
+    /// The index of the face inside the file.
+    pub index: u32,
+    pub name: String,
+}
+
+impl fmt::Display for FontFace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}: {}", self.index, self.name)
+    }
+}
+
+/// A glyph of a face no character maps to, e.g. a stylistic alternate only
+/// reachable through OpenType features.
+pub struct UnencodedGlyph {
+    pub id: u16,
+    /// The glyph's name from the `post` or `CFF` table, e.g. `a.alt`.
+    pub name: Option<String>,
+}
+
+/// A font file opened for browsing.
+pub struct FontFile {
+    pub path: PathBuf,
+    pub data: Vec<u8>,
+    pub faces: Vec<FontFace>,
+    /// The currently browsed face, an index into `faces`.
+    pub face: usize,
+}
+
+impl FontFile {
+    pub fn open(path: PathBuf) -> std::io::Result<Self> {
+        let data = std::fs::read(&path)?;
+
+        // `None` if this is not a collection.
+        let faces = (0..ttf_parser::fonts_in_collection(&data).unwrap_or(1))
+            .filter_map(|index| {
+                let face = ttf_parser::Face::parse(&data, index).ok()?;
+
+                Some(FontFace {
+                    index,
+                    name: face_name(&face).unwrap_or_else(|| format!("Face {index}")),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if faces.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "no font faces found",
+            ));
+        }
+
+        Ok(Self {
+            path,
+            data,
+            faces,
+            face: 0,
+        })
+    }
+
+    pub fn current_face(&self) -> &FontFace {
+        &self.faces[self.face]
+    }
+
+    /// The glyphs of the current face that no Unicode `cmap` subtable maps
+    /// a character to, in glyph id order.
+    pub fn unencoded_glyphs(&self) -> Vec<UnencodedGlyph> {
+        let Ok(face) = ttf_parser::Face::parse(&self.data, self.current_face().index) else {
+            return Vec::new();
+        };
+
+        let mut encoded = HashSet::new();
+        for subtable in face
+            .tables()
+            .cmap
+            .into_iter()
+            .flat_map(|cmap| cmap.subtables)
+            .filter(|subtable| subtable.is_unicode())
+        {
+            subtable.codepoints(|codepoint| {
+                if let Some(id) = subtable.glyph_index(codepoint) {
+                    encoded.insert(id.0);
+                }
+            });
+        }
+
+        (0..face.number_of_glyphs())
+            .filter(|id| !encoded.contains(id))
+            .map(|id| UnencodedGlyph {
+                id,
+                name: face.glyph_name(ttf_parser::GlyphId(id)).map(str::to_string),
+            })
+            .collect()
+    }
+
+    /// The id of the glyph the current face maps each of `chars` to. Those
+    /// it has no glyph for are left out.
+    pub fn glyph_ids(&self, chars: impl IntoIterator<Item = char>) -> HashMap<char, u16> {
+        let Ok(face) = ttf_parser::Face::parse(&self.data, self.current_face().index) else {
+            return HashMap::new();
+        };
+
+        chars
+            .into_iter()
+            .filter_map(|chr| Some((chr, face.glyph_index(chr)?.0)))
+            .collect()
+    }
+
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+fn face_name(face: &ttf_parser::Face) -> Option<String> {
+    [ttf_parser::name_id::FULL_NAME, ttf_parser::name_id::FAMILY]
+        .into_iter()
+        .find_map(|name_id| {
+            face.names()
+                .into_iter()
+                .filter(|name| name_id == name.name_id)
+                .find_map(|name| name.to_string())
+        })
+}
@@ -13,9 +13,25 @@ use std::error::Error;
 };*/
 
 mod app;
+mod character_set;
+mod copy_format;
+mod decomposition;
+mod derived_name;
+mod extra_font;
+mod font_file;
+mod name_correction;
+mod numeric_value;
+mod raster;
+mod search;
+mod similarity;
+mod string_inspector;
+mod subset_font;
+mod variants;
 pub use app::GlyphanaApp;
 
+pub const ALMOST_EQUAL: char = '≈';
 pub const CANCELLATION: char = '🗙';
+pub const CLOCK: char = '🕘';
 pub const COG_WHEEL: char = '⚙';
 pub const HAMBURGER: char = '☰';
 pub const MAGNIFIER: char = '🔍';
@@ -24,6 +40,21 @@ pub const LOWER_UPPER_CASE: char = '🗛';
 pub const PUSH_PIN: char = '📌';
 pub const SUBSET: char = '⊂';
 
+/// Every icon above with the text to show instead if none of the fonts
+/// used for the UI has a glyph for it.
+pub const UI_ICONS: [(char, &str); 10] = [
+    (ALMOST_EQUAL, "Fuzzy"),
+    (CANCELLATION, "Clear"),
+    (CLOCK, "History"),
+    (COG_WHEEL, "Preferences"),
+    (HAMBURGER, "Menu"),
+    (MAGNIFIER, "Search"),
+    (NAME_BADGE, "Name"),
+    (LOWER_UPPER_CASE, "Aa"),
+    (PUSH_PIN, "Pin"),
+    (SUBSET, "Subset"),
+];
+
 pub const NOTO_SANS: &str = "noto-sans";
 flate!(pub static NOTO_SANS_FONT: [u8] from "assets/NotoSans-Regular.otf");
 
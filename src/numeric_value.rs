@@ -0,0 +1,820 @@
+// The numeric values of characters, from the Numeric_Value property in
+// UnicodeData.txt and the Unihan database (Unicode 14.0.0).
+
+// Runs of consecutive codepoints: (first, last, numerator, denominator) of
+// the value of `first`. Each codepoint after it has a value one higher,
+// e.g. the digits of a script.
+static NUMERIC_VALUES: [(u32, u32, i64, u32); 779] = [
+    (0x0030, 0x0039, 0, 1),
+    (0x00B2, 0x00B3, 2, 1),
+    (0x00B9, 0x00B9, 1, 1),
+    (0x00BC, 0x00BC, 1, 4),
+    (0x00BD, 0x00BD, 1, 2),
+    (0x00BE, 0x00BE, 3, 4),
+    (0x0660, 0x0669, 0, 1),
+    (0x06F0, 0x06F9, 0, 1),
+    (0x07C0, 0x07C9, 0, 1),
+    (0x0966, 0x096F, 0, 1),
+    (0x09E6, 0x09EF, 0, 1),
+    (0x09F4, 0x09F4, 1, 16),
+    (0x09F5, 0x09F5, 1, 8),
+    (0x09F6, 0x09F6, 3, 16),
+    (0x09F7, 0x09F7, 1, 4),
+    (0x09F8, 0x09F8, 3, 4),
+    (0x09F9, 0x09F9, 16, 1),
+    (0x0A66, 0x0A6F, 0, 1),
+    (0x0AE6, 0x0AEF, 0, 1),
+    (0x0B66, 0x0B6F, 0, 1),
+    (0x0B72, 0x0B72, 1, 4),
+    (0x0B73, 0x0B73, 1, 2),
+    (0x0B74, 0x0B74, 3, 4),
+    (0x0B75, 0x0B75, 1, 16),
+    (0x0B76, 0x0B76, 1, 8),
+    (0x0B77, 0x0B77, 3, 16),
+    (0x0BE6, 0x0BF0, 0, 1),
+    (0x0BF1, 0x0BF1, 100, 1),
+    (0x0BF2, 0x0BF2, 1000, 1),
+    (0x0C66, 0x0C6F, 0, 1),
+    (0x0C78, 0x0C7B, 0, 1),
+    (0x0C7C, 0x0C7E, 1, 1),
+    (0x0CE6, 0x0CEF, 0, 1),
+    (0x0D58, 0x0D58, 1, 160),
+    (0x0D59, 0x0D59, 1, 40),
+    (0x0D5A, 0x0D5A, 3, 80),
+    (0x0D5B, 0x0D5B, 1, 20),
+    (0x0D5C, 0x0D5C, 1, 10),
+    (0x0D5D, 0x0D5D, 3, 20),
+    (0x0D5E, 0x0D5E, 1, 5),
+    (0x0D66, 0x0D70, 0, 1),
+    (0x0D71, 0x0D71, 100, 1),
+    (0x0D72, 0x0D72, 1000, 1),
+    (0x0D73, 0x0D73, 1, 4),
+    (0x0D74, 0x0D74, 1, 2),
+    (0x0D75, 0x0D75, 3, 4),
+    (0x0D76, 0x0D76, 1, 16),
+    (0x0D77, 0x0D77, 1, 8),
+    (0x0D78, 0x0D78, 3, 16),
+    (0x0DE6, 0x0DEF, 0, 1),
+    (0x0E50, 0x0E59, 0, 1),
+    (0x0ED0, 0x0ED9, 0, 1),
+    (0x0F20, 0x0F29, 0, 1),
+    (0x0F2A, 0x0F2A, 1, 2),
+    (0x0F2B, 0x0F2B, 3, 2),
+    (0x0F2C, 0x0F2C, 5, 2),
+    (0x0F2D, 0x0F2D, 7, 2),
+    (0x0F2E, 0x0F2E, 9, 2),
+    (0x0F2F, 0x0F2F, 11, 2),
+    (0x0F30, 0x0F30, 13, 2),
+    (0x0F31, 0x0F31, 15, 2),
+    (0x0F32, 0x0F32, 17, 2),
+    (0x0F33, 0x0F33, -1, 2),
+    (0x1040, 0x1049, 0, 1),
+    (0x1090, 0x1099, 0, 1),
+    (0x1369, 0x1372, 1, 1),
+    (0x1373, 0x1373, 20, 1),
+    (0x1374, 0x1374, 30, 1),
+    (0x1375, 0x1375, 40, 1),
+    (0x1376, 0x1376, 50, 1),
+    (0x1377, 0x1377, 60, 1),
+    (0x1378, 0x1378, 70, 1),
+    (0x1379, 0x1379, 80, 1),
+    (0x137A, 0x137A, 90, 1),
+    (0x137B, 0x137B, 100, 1),
+    (0x137C, 0x137C, 10000, 1),
+    (0x16EE, 0x16F0, 17, 1),
+    (0x17E0, 0x17E9, 0, 1),
+    (0x17F0, 0x17F9, 0, 1),
+    (0x1810, 0x1819, 0, 1),
+    (0x1946, 0x194F, 0, 1),
+    (0x19D0, 0x19D9, 0, 1),
+    (0x19DA, 0x19DA, 1, 1),
+    (0x1A80, 0x1A89, 0, 1),
+    (0x1A90, 0x1A99, 0, 1),
+    (0x1B50, 0x1B59, 0, 1),
+    (0x1BB0, 0x1BB9, 0, 1),
+    (0x1C40, 0x1C49, 0, 1),
+    (0x1C50, 0x1C59, 0, 1),
+    (0x2070, 0x2070, 0, 1),
+    (0x2074, 0x2079, 4, 1),
+    (0x2080, 0x2089, 0, 1),
+    (0x2150, 0x2150, 1, 7),
+    (0x2151, 0x2151, 1, 9),
+    (0x2152, 0x2152, 1, 10),
+    (0x2153, 0x2153, 1, 3),
+    (0x2154, 0x2154, 2, 3),
+    (0x2155, 0x2155, 1, 5),
+    (0x2156, 0x2156, 2, 5),
+    (0x2157, 0x2157, 3, 5),
+    (0x2158, 0x2158, 4, 5),
+    (0x2159, 0x2159, 1, 6),
+    (0x215A, 0x215A, 5, 6),
+    (0x215B, 0x215B, 1, 8),
+    (0x215C, 0x215C, 3, 8),
+    (0x215D, 0x215D, 5, 8),
+    (0x215E, 0x215E, 7, 8),
+    (0x215F, 0x215F, 1, 1),
+    (0x2160, 0x216B, 1, 1),
+    (0x216C, 0x216C, 50, 1),
+    (0x216D, 0x216D, 100, 1),
+    (0x216E, 0x216E, 500, 1),
+    (0x216F, 0x216F, 1000, 1),
+    (0x2170, 0x217B, 1, 1),
+    (0x217C, 0x217C, 50, 1),
+    (0x217D, 0x217D, 100, 1),
+    (0x217E, 0x217E, 500, 1),
+    (0x217F, 0x217F, 1000, 1),
+    (0x2180, 0x2180, 1000, 1),
+    (0x2181, 0x2181, 5000, 1),
+    (0x2182, 0x2182, 10000, 1),
+    (0x2185, 0x2185, 6, 1),
+    (0x2186, 0x2186, 50, 1),
+    (0x2187, 0x2187, 50000, 1),
+    (0x2188, 0x2188, 100000, 1),
+    (0x2189, 0x2189, 0, 1),
+    (0x2460, 0x2473, 1, 1),
+    (0x2474, 0x2487, 1, 1),
+    (0x2488, 0x249B, 1, 1),
+    (0x24EA, 0x24EA, 0, 1),
+    (0x24EB, 0x24F4, 11, 1),
+    (0x24F5, 0x24FE, 1, 1),
+    (0x24FF, 0x24FF, 0, 1),
+    (0x2776, 0x277F, 1, 1),
+    (0x2780, 0x2789, 1, 1),
+    (0x278A, 0x2793, 1, 1),
+    (0x2CFD, 0x2CFD, 1, 2),
+    (0x3007, 0x3007, 0, 1),
+    (0x3021, 0x3029, 1, 1),
+    (0x3038, 0x3038, 10, 1),
+    (0x3039, 0x3039, 20, 1),
+    (0x303A, 0x303A, 30, 1),
+    (0x3192, 0x3195, 1, 1),
+    (0x3220, 0x3229, 1, 1),
+    (0x3248, 0x3248, 10, 1),
+    (0x3249, 0x3249, 20, 1),
+    (0x324A, 0x324A, 30, 1),
+    (0x324B, 0x324B, 40, 1),
+    (0x324C, 0x324C, 50, 1),
+    (0x324D, 0x324D, 60, 1),
+    (0x324E, 0x324E, 70, 1),
+    (0x324F, 0x324F, 80, 1),
+    (0x3251, 0x325F, 21, 1),
+    (0x3280, 0x3289, 1, 1),
+    (0x32B1, 0x32BF, 36, 1),
+    (0x3405, 0x3405, 5, 1),
+    (0x3483, 0x3483, 2, 1),
+    (0x382A, 0x382A, 5, 1),
+    (0x3B4D, 0x3B4D, 7, 1),
+    (0x4E00, 0x4E00, 1, 1),
+    (0x4E03, 0x4E03, 7, 1),
+    (0x4E07, 0x4E07, 10000, 1),
+    (0x4E09, 0x4E09, 3, 1),
+    (0x4E5D, 0x4E5D, 9, 1),
+    (0x4E8C, 0x4E8C, 2, 1),
+    (0x4E94, 0x4E94, 5, 1),
+    (0x4E96, 0x4E96, 4, 1),
+    (0x4EBF, 0x4EBF, 100000000, 1),
+    (0x4EC0, 0x4EC0, 10, 1),
+    (0x4EDF, 0x4EDF, 1000, 1),
+    (0x4EE8, 0x4EE8, 3, 1),
+    (0x4F0D, 0x4F0D, 5, 1),
+    (0x4F70, 0x4F70, 100, 1),
+    (0x5104, 0x5104, 100000000, 1),
+    (0x5146, 0x5146, 1000000000000, 1),
+    (0x5169, 0x5169, 2, 1),
+    (0x516B, 0x516B, 8, 1),
+    (0x516D, 0x516D, 6, 1),
+    (0x5341, 0x5341, 10, 1),
+    (0x5343, 0x5343, 1000, 1),
+    (0x5344, 0x5344, 20, 1),
+    (0x5345, 0x5345, 30, 1),
+    (0x534C, 0x534C, 40, 1),
+    (0x53C1, 0x53C1, 3, 1),
+    (0x53C2, 0x53C2, 3, 1),
+    (0x53C3, 0x53C3, 3, 1),
+    (0x53C4, 0x53C4, 3, 1),
+    (0x56DB, 0x56DB, 4, 1),
+    (0x58F1, 0x58F1, 1, 1),
+    (0x58F9, 0x58F9, 1, 1),
+    (0x5E7A, 0x5E7A, 1, 1),
+    (0x5EFE, 0x5EFE, 9, 1),
+    (0x5EFF, 0x5EFF, 20, 1),
+    (0x5F0C, 0x5F0E, 1, 1),
+    (0x5F10, 0x5F10, 2, 1),
+    (0x62FE, 0x62FE, 10, 1),
+    (0x634C, 0x634C, 8, 1),
+    (0x67D2, 0x67D2, 7, 1),
+    (0x6F06, 0x6F06, 7, 1),
+    (0x7396, 0x7396, 9, 1),
+    (0x767E, 0x767E, 100, 1),
+    (0x8086, 0x8086, 4, 1),
+    (0x842C, 0x842C, 10000, 1),
+    (0x8CAE, 0x8CAE, 2, 1),
+    (0x8CB3, 0x8CB3, 2, 1),
+    (0x8D30, 0x8D30, 2, 1),
+    (0x9621, 0x9621, 1000, 1),
+    (0x9646, 0x9646, 6, 1),
+    (0x964C, 0x964C, 100, 1),
+    (0x9678, 0x9678, 6, 1),
+    (0x96F6, 0x96F6, 0, 1),
+    (0xA620, 0xA629, 0, 1),
+    (0xA6E6, 0xA6EE, 1, 1),
+    (0xA6EF, 0xA6EF, 0, 1),
+    (0xA830, 0xA830, 1, 4),
+    (0xA831, 0xA831, 1, 2),
+    (0xA832, 0xA832, 3, 4),
+    (0xA833, 0xA833, 1, 16),
+    (0xA834, 0xA834, 1, 8),
+    (0xA835, 0xA835, 3, 16),
+    (0xA8D0, 0xA8D9, 0, 1),
+    (0xA900, 0xA909, 0, 1),
+    (0xA9D0, 0xA9D9, 0, 1),
+    (0xA9F0, 0xA9F9, 0, 1),
+    (0xAA50, 0xAA59, 0, 1),
+    (0xABF0, 0xABF9, 0, 1),
+    (0xF96B, 0xF96B, 3, 1),
+    (0xF973, 0xF973, 10, 1),
+    (0xF978, 0xF978, 2, 1),
+    (0xF9B2, 0xF9B2, 0, 1),
+    (0xF9D1, 0xF9D1, 6, 1),
+    (0xF9D3, 0xF9D3, 6, 1),
+    (0xF9FD, 0xF9FD, 10, 1),
+    (0xFF10, 0xFF19, 0, 1),
+    (0x10107, 0x10110, 1, 1),
+    (0x10111, 0x10111, 20, 1),
+    (0x10112, 0x10112, 30, 1),
+    (0x10113, 0x10113, 40, 1),
+    (0x10114, 0x10114, 50, 1),
+    (0x10115, 0x10115, 60, 1),
+    (0x10116, 0x10116, 70, 1),
+    (0x10117, 0x10117, 80, 1),
+    (0x10118, 0x10118, 90, 1),
+    (0x10119, 0x10119, 100, 1),
+    (0x1011A, 0x1011A, 200, 1),
+    (0x1011B, 0x1011B, 300, 1),
+    (0x1011C, 0x1011C, 400, 1),
+    (0x1011D, 0x1011D, 500, 1),
+    (0x1011E, 0x1011E, 600, 1),
+    (0x1011F, 0x1011F, 700, 1),
+    (0x10120, 0x10120, 800, 1),
+    (0x10121, 0x10121, 900, 1),
+    (0x10122, 0x10122, 1000, 1),
+    (0x10123, 0x10123, 2000, 1),
+    (0x10124, 0x10124, 3000, 1),
+    (0x10125, 0x10125, 4000, 1),
+    (0x10126, 0x10126, 5000, 1),
+    (0x10127, 0x10127, 6000, 1),
+    (0x10128, 0x10128, 7000, 1),
+    (0x10129, 0x10129, 8000, 1),
+    (0x1012A, 0x1012A, 9000, 1),
+    (0x1012B, 0x1012B, 10000, 1),
+    (0x1012C, 0x1012C, 20000, 1),
+    (0x1012D, 0x1012D, 30000, 1),
+    (0x1012E, 0x1012E, 40000, 1),
+    (0x1012F, 0x1012F, 50000, 1),
+    (0x10130, 0x10130, 60000, 1),
+    (0x10131, 0x10131, 70000, 1),
+    (0x10132, 0x10132, 80000, 1),
+    (0x10133, 0x10133, 90000, 1),
+    (0x10140, 0x10140, 1, 4),
+    (0x10141, 0x10141, 1, 2),
+    (0x10142, 0x10142, 1, 1),
+    (0x10143, 0x10143, 5, 1),
+    (0x10144, 0x10144, 50, 1),
+    (0x10145, 0x10145, 500, 1),
+    (0x10146, 0x10146, 5000, 1),
+    (0x10147, 0x10147, 50000, 1),
+    (0x10148, 0x10148, 5, 1),
+    (0x10149, 0x10149, 10, 1),
+    (0x1014A, 0x1014A, 50, 1),
+    (0x1014B, 0x1014B, 100, 1),
+    (0x1014C, 0x1014C, 500, 1),
+    (0x1014D, 0x1014D, 1000, 1),
+    (0x1014E, 0x1014E, 5000, 1),
+    (0x1014F, 0x1014F, 5, 1),
+    (0x10150, 0x10150, 10, 1),
+    (0x10151, 0x10151, 50, 1),
+    (0x10152, 0x10152, 100, 1),
+    (0x10153, 0x10153, 500, 1),
+    (0x10154, 0x10154, 1000, 1),
+    (0x10155, 0x10155, 10000, 1),
+    (0x10156, 0x10156, 50000, 1),
+    (0x10157, 0x10157, 10, 1),
+    (0x10158, 0x10158, 1, 1),
+    (0x10159, 0x10159, 1, 1),
+    (0x1015A, 0x1015B, 1, 1),
+    (0x1015C, 0x1015C, 2, 1),
+    (0x1015D, 0x1015D, 2, 1),
+    (0x1015E, 0x1015E, 2, 1),
+    (0x1015F, 0x1015F, 5, 1),
+    (0x10160, 0x10160, 10, 1),
+    (0x10161, 0x10161, 10, 1),
+    (0x10162, 0x10162, 10, 1),
+    (0x10163, 0x10163, 10, 1),
+    (0x10164, 0x10164, 10, 1),
+    (0x10165, 0x10165, 30, 1),
+    (0x10166, 0x10166, 50, 1),
+    (0x10167, 0x10167, 50, 1),
+    (0x10168, 0x10168, 50, 1),
+    (0x10169, 0x10169, 50, 1),
+    (0x1016A, 0x1016A, 100, 1),
+    (0x1016B, 0x1016B, 300, 1),
+    (0x1016C, 0x1016C, 500, 1),
+    (0x1016D, 0x1016D, 500, 1),
+    (0x1016E, 0x1016E, 500, 1),
+    (0x1016F, 0x1016F, 500, 1),
+    (0x10170, 0x10170, 500, 1),
+    (0x10171, 0x10171, 1000, 1),
+    (0x10172, 0x10172, 5000, 1),
+    (0x10173, 0x10173, 5, 1),
+    (0x10174, 0x10174, 50, 1),
+    (0x10175, 0x10175, 1, 2),
+    (0x10176, 0x10176, 1, 2),
+    (0x10177, 0x10177, 2, 3),
+    (0x10178, 0x10178, 3, 4),
+    (0x1018A, 0x1018A, 0, 1),
+    (0x1018B, 0x1018B, 1, 4),
+    (0x102E1, 0x102EA, 1, 1),
+    (0x102EB, 0x102EB, 20, 1),
+    (0x102EC, 0x102EC, 30, 1),
+    (0x102ED, 0x102ED, 40, 1),
+    (0x102EE, 0x102EE, 50, 1),
+    (0x102EF, 0x102EF, 60, 1),
+    (0x102F0, 0x102F0, 70, 1),
+    (0x102F1, 0x102F1, 80, 1),
+    (0x102F2, 0x102F2, 90, 1),
+    (0x102F3, 0x102F3, 100, 1),
+    (0x102F4, 0x102F4, 200, 1),
+    (0x102F5, 0x102F5, 300, 1),
+    (0x102F6, 0x102F6, 400, 1),
+    (0x102F7, 0x102F7, 500, 1),
+    (0x102F8, 0x102F8, 600, 1),
+    (0x102F9, 0x102F9, 700, 1),
+    (0x102FA, 0x102FA, 800, 1),
+    (0x102FB, 0x102FB, 900, 1),
+    (0x10320, 0x10320, 1, 1),
+    (0x10321, 0x10321, 5, 1),
+    (0x10322, 0x10322, 10, 1),
+    (0x10323, 0x10323, 50, 1),
+    (0x10341, 0x10341, 90, 1),
+    (0x1034A, 0x1034A, 900, 1),
+    (0x103D1, 0x103D2, 1, 1),
+    (0x103D3, 0x103D3, 10, 1),
+    (0x103D4, 0x103D4, 20, 1),
+    (0x103D5, 0x103D5, 100, 1),
+    (0x104A0, 0x104A9, 0, 1),
+    (0x10858, 0x1085A, 1, 1),
+    (0x1085B, 0x1085B, 10, 1),
+    (0x1085C, 0x1085C, 20, 1),
+    (0x1085D, 0x1085D, 100, 1),
+    (0x1085E, 0x1085E, 1000, 1),
+    (0x1085F, 0x1085F, 10000, 1),
+    (0x10879, 0x1087D, 1, 1),
+    (0x1087E, 0x1087E, 10, 1),
+    (0x1087F, 0x1087F, 20, 1),
+    (0x108A7, 0x108AA, 1, 1),
+    (0x108AB, 0x108AC, 4, 1),
+    (0x108AD, 0x108AD, 10, 1),
+    (0x108AE, 0x108AE, 20, 1),
+    (0x108AF, 0x108AF, 100, 1),
+    (0x108FB, 0x108FB, 1, 1),
+    (0x108FC, 0x108FC, 5, 1),
+    (0x108FD, 0x108FD, 10, 1),
+    (0x108FE, 0x108FE, 20, 1),
+    (0x108FF, 0x108FF, 100, 1),
+    (0x10916, 0x10916, 1, 1),
+    (0x10917, 0x10917, 10, 1),
+    (0x10918, 0x10918, 20, 1),
+    (0x10919, 0x10919, 100, 1),
+    (0x1091A, 0x1091B, 2, 1),
+    (0x109BC, 0x109BC, 11, 12),
+    (0x109BD, 0x109BD, 1, 2),
+    (0x109C0, 0x109C9, 1, 1),
+    (0x109CA, 0x109CA, 20, 1),
+    (0x109CB, 0x109CB, 30, 1),
+    (0x109CC, 0x109CC, 40, 1),
+    (0x109CD, 0x109CD, 50, 1),
+    (0x109CE, 0x109CE, 60, 1),
+    (0x109CF, 0x109CF, 70, 1),
+    (0x109D2, 0x109D2, 100, 1),
+    (0x109D3, 0x109D3, 200, 1),
+    (0x109D4, 0x109D4, 300, 1),
+    (0x109D5, 0x109D5, 400, 1),
+    (0x109D6, 0x109D6, 500, 1),
+    (0x109D7, 0x109D7, 600, 1),
+    (0x109D8, 0x109D8, 700, 1),
+    (0x109D9, 0x109D9, 800, 1),
+    (0x109DA, 0x109DA, 900, 1),
+    (0x109DB, 0x109DB, 1000, 1),
+    (0x109DC, 0x109DC, 2000, 1),
+    (0x109DD, 0x109DD, 3000, 1),
+    (0x109DE, 0x109DE, 4000, 1),
+    (0x109DF, 0x109DF, 5000, 1),
+    (0x109E0, 0x109E0, 6000, 1),
+    (0x109E1, 0x109E1, 7000, 1),
+    (0x109E2, 0x109E2, 8000, 1),
+    (0x109E3, 0x109E3, 9000, 1),
+    (0x109E4, 0x109E4, 10000, 1),
+    (0x109E5, 0x109E5, 20000, 1),
+    (0x109E6, 0x109E6, 30000, 1),
+    (0x109E7, 0x109E7, 40000, 1),
+    (0x109E8, 0x109E8, 50000, 1),
+    (0x109E9, 0x109E9, 60000, 1),
+    (0x109EA, 0x109EA, 70000, 1),
+    (0x109EB, 0x109EB, 80000, 1),
+    (0x109EC, 0x109EC, 90000, 1),
+    (0x109ED, 0x109ED, 100000, 1),
+    (0x109EE, 0x109EE, 200000, 1),
+    (0x109EF, 0x109EF, 300000, 1),
+    (0x109F0, 0x109F0, 400000, 1),
+    (0x109F1, 0x109F1, 500000, 1),
+    (0x109F2, 0x109F2, 600000, 1),
+    (0x109F3, 0x109F3, 700000, 1),
+    (0x109F4, 0x109F4, 800000, 1),
+    (0x109F5, 0x109F5, 900000, 1),
+    (0x109F6, 0x109F6, 1, 12),
+    (0x109F7, 0x109F7, 1, 6),
+    (0x109F8, 0x109F8, 1, 4),
+    (0x109F9, 0x109F9, 1, 3),
+    (0x109FA, 0x109FA, 5, 12),
+    (0x109FB, 0x109FB, 1, 2),
+    (0x109FC, 0x109FC, 7, 12),
+    (0x109FD, 0x109FD, 2, 3),
+    (0x109FE, 0x109FE, 3, 4),
+    (0x109FF, 0x109FF, 5, 6),
+    (0x10A40, 0x10A43, 1, 1),
+    (0x10A44, 0x10A44, 10, 1),
+    (0x10A45, 0x10A45, 20, 1),
+    (0x10A46, 0x10A46, 100, 1),
+    (0x10A47, 0x10A47, 1000, 1),
+    (0x10A48, 0x10A48, 1, 2),
+    (0x10A7D, 0x10A7D, 1, 1),
+    (0x10A7E, 0x10A7E, 50, 1),
+    (0x10A9D, 0x10A9D, 1, 1),
+    (0x10A9E, 0x10A9E, 10, 1),
+    (0x10A9F, 0x10A9F, 20, 1),
+    (0x10AEB, 0x10AEB, 1, 1),
+    (0x10AEC, 0x10AEC, 5, 1),
+    (0x10AED, 0x10AED, 10, 1),
+    (0x10AEE, 0x10AEE, 20, 1),
+    (0x10AEF, 0x10AEF, 100, 1),
+    (0x10B58, 0x10B5B, 1, 1),
+    (0x10B5C, 0x10B5C, 10, 1),
+    (0x10B5D, 0x10B5D, 20, 1),
+    (0x10B5E, 0x10B5E, 100, 1),
+    (0x10B5F, 0x10B5F, 1000, 1),
+    (0x10B78, 0x10B7B, 1, 1),
+    (0x10B7C, 0x10B7C, 10, 1),
+    (0x10B7D, 0x10B7D, 20, 1),
+    (0x10B7E, 0x10B7E, 100, 1),
+    (0x10B7F, 0x10B7F, 1000, 1),
+    (0x10BA9, 0x10BAC, 1, 1),
+    (0x10BAD, 0x10BAD, 10, 1),
+    (0x10BAE, 0x10BAE, 20, 1),
+    (0x10BAF, 0x10BAF, 100, 1),
+    (0x10CFA, 0x10CFA, 1, 1),
+    (0x10CFB, 0x10CFB, 5, 1),
+    (0x10CFC, 0x10CFC, 10, 1),
+    (0x10CFD, 0x10CFD, 50, 1),
+    (0x10CFE, 0x10CFE, 100, 1),
+    (0x10CFF, 0x10CFF, 1000, 1),
+    (0x10D30, 0x10D39, 0, 1),
+    (0x10E60, 0x10E69, 1, 1),
+    (0x10E6A, 0x10E6A, 20, 1),
+    (0x10E6B, 0x10E6B, 30, 1),
+    (0x10E6C, 0x10E6C, 40, 1),
+    (0x10E6D, 0x10E6D, 50, 1),
+    (0x10E6E, 0x10E6E, 60, 1),
+    (0x10E6F, 0x10E6F, 70, 1),
+    (0x10E70, 0x10E70, 80, 1),
+    (0x10E71, 0x10E71, 90, 1),
+    (0x10E72, 0x10E72, 100, 1),
+    (0x10E73, 0x10E73, 200, 1),
+    (0x10E74, 0x10E74, 300, 1),
+    (0x10E75, 0x10E75, 400, 1),
+    (0x10E76, 0x10E76, 500, 1),
+    (0x10E77, 0x10E77, 600, 1),
+    (0x10E78, 0x10E78, 700, 1),
+    (0x10E79, 0x10E79, 800, 1),
+    (0x10E7A, 0x10E7A, 900, 1),
+    (0x10E7B, 0x10E7B, 1, 2),
+    (0x10E7C, 0x10E7C, 1, 4),
+    (0x10E7D, 0x10E7D, 1, 3),
+    (0x10E7E, 0x10E7E, 2, 3),
+    (0x10F1D, 0x10F21, 1, 1),
+    (0x10F22, 0x10F22, 10, 1),
+    (0x10F23, 0x10F23, 20, 1),
+    (0x10F24, 0x10F24, 30, 1),
+    (0x10F25, 0x10F25, 100, 1),
+    (0x10F26, 0x10F26, 1, 2),
+    (0x10F51, 0x10F51, 1, 1),
+    (0x10F52, 0x10F52, 10, 1),
+    (0x10F53, 0x10F53, 20, 1),
+    (0x10F54, 0x10F54, 100, 1),
+    (0x10FC5, 0x10FC8, 1, 1),
+    (0x10FC9, 0x10FC9, 10, 1),
+    (0x10FCA, 0x10FCA, 20, 1),
+    (0x10FCB, 0x10FCB, 100, 1),
+    (0x11052, 0x1105B, 1, 1),
+    (0x1105C, 0x1105C, 20, 1),
+    (0x1105D, 0x1105D, 30, 1),
+    (0x1105E, 0x1105E, 40, 1),
+    (0x1105F, 0x1105F, 50, 1),
+    (0x11060, 0x11060, 60, 1),
+    (0x11061, 0x11061, 70, 1),
+    (0x11062, 0x11062, 80, 1),
+    (0x11063, 0x11063, 90, 1),
+    (0x11064, 0x11064, 100, 1),
+    (0x11065, 0x11065, 1000, 1),
+    (0x11066, 0x1106F, 0, 1),
+    (0x110F0, 0x110F9, 0, 1),
+    (0x11136, 0x1113F, 0, 1),
+    (0x111D0, 0x111D9, 0, 1),
+    (0x111E1, 0x111EA, 1, 1),
+    (0x111EB, 0x111EB, 20, 1),
+    (0x111EC, 0x111EC, 30, 1),
+    (0x111ED, 0x111ED, 40, 1),
+    (0x111EE, 0x111EE, 50, 1),
+    (0x111EF, 0x111EF, 60, 1),
+    (0x111F0, 0x111F0, 70, 1),
+    (0x111F1, 0x111F1, 80, 1),
+    (0x111F2, 0x111F2, 90, 1),
+    (0x111F3, 0x111F3, 100, 1),
+    (0x111F4, 0x111F4, 1000, 1),
+    (0x112F0, 0x112F9, 0, 1),
+    (0x11450, 0x11459, 0, 1),
+    (0x114D0, 0x114D9, 0, 1),
+    (0x11650, 0x11659, 0, 1),
+    (0x116C0, 0x116C9, 0, 1),
+    (0x11730, 0x1173A, 0, 1),
+    (0x1173B, 0x1173B, 20, 1),
+    (0x118E0, 0x118EA, 0, 1),
+    (0x118EB, 0x118EB, 20, 1),
+    (0x118EC, 0x118EC, 30, 1),
+    (0x118ED, 0x118ED, 40, 1),
+    (0x118EE, 0x118EE, 50, 1),
+    (0x118EF, 0x118EF, 60, 1),
+    (0x118F0, 0x118F0, 70, 1),
+    (0x118F1, 0x118F1, 80, 1),
+    (0x118F2, 0x118F2, 90, 1),
+    (0x11950, 0x11959, 0, 1),
+    (0x11C50, 0x11C59, 0, 1),
+    (0x11C5A, 0x11C63, 1, 1),
+    (0x11C64, 0x11C64, 20, 1),
+    (0x11C65, 0x11C65, 30, 1),
+    (0x11C66, 0x11C66, 40, 1),
+    (0x11C67, 0x11C67, 50, 1),
+    (0x11C68, 0x11C68, 60, 1),
+    (0x11C69, 0x11C69, 70, 1),
+    (0x11C6A, 0x11C6A, 80, 1),
+    (0x11C6B, 0x11C6B, 90, 1),
+    (0x11C6C, 0x11C6C, 100, 1),
+    (0x11D50, 0x11D59, 0, 1),
+    (0x11DA0, 0x11DA9, 0, 1),
+    (0x11FC0, 0x11FC0, 1, 320),
+    (0x11FC1, 0x11FC1, 1, 160),
+    (0x11FC2, 0x11FC2, 1, 80),
+    (0x11FC3, 0x11FC3, 1, 64),
+    (0x11FC4, 0x11FC4, 1, 40),
+    (0x11FC5, 0x11FC5, 1, 32),
+    (0x11FC6, 0x11FC6, 3, 80),
+    (0x11FC7, 0x11FC7, 3, 64),
+    (0x11FC8, 0x11FC8, 1, 20),
+    (0x11FC9, 0x11FC9, 1, 16),
+    (0x11FCA, 0x11FCA, 1, 16),
+    (0x11FCB, 0x11FCB, 1, 10),
+    (0x11FCC, 0x11FCC, 1, 8),
+    (0x11FCD, 0x11FCD, 3, 20),
+    (0x11FCE, 0x11FCE, 3, 16),
+    (0x11FCF, 0x11FCF, 1, 5),
+    (0x11FD0, 0x11FD0, 1, 4),
+    (0x11FD1, 0x11FD1, 1, 2),
+    (0x11FD2, 0x11FD2, 1, 2),
+    (0x11FD3, 0x11FD3, 3, 4),
+    (0x11FD4, 0x11FD4, 1, 320),
+    (0x12400, 0x12407, 2, 1),
+    (0x12408, 0x1240E, 3, 1),
+    (0x1240F, 0x12414, 4, 1),
+    (0x12415, 0x1241D, 1, 1),
+    (0x1241E, 0x12422, 1, 1),
+    (0x12423, 0x12424, 2, 1),
+    (0x12425, 0x1242B, 3, 1),
+    (0x1242C, 0x1242E, 1, 1),
+    (0x1242F, 0x12431, 3, 1),
+    (0x12432, 0x12432, 216000, 1),
+    (0x12433, 0x12433, 432000, 1),
+    (0x12434, 0x12436, 1, 1),
+    (0x12437, 0x12439, 3, 1),
+    (0x1243A, 0x1243A, 3, 1),
+    (0x1243B, 0x1243C, 3, 1),
+    (0x1243D, 0x1243D, 4, 1),
+    (0x1243E, 0x1243E, 4, 1),
+    (0x1243F, 0x1243F, 4, 1),
+    (0x12440, 0x12441, 6, 1),
+    (0x12442, 0x12442, 7, 1),
+    (0x12443, 0x12444, 7, 1),
+    (0x12445, 0x12446, 8, 1),
+    (0x12447, 0x12447, 9, 1),
+    (0x12448, 0x12448, 9, 1),
+    (0x12449, 0x12449, 9, 1),
+    (0x1244A, 0x1244E, 2, 1),
+    (0x1244F, 0x12452, 1, 1),
+    (0x12453, 0x12454, 4, 1),
+    (0x12455, 0x12455, 5, 1),
+    (0x12456, 0x12457, 2, 1),
+    (0x12458, 0x12459, 1, 1),
+    (0x1245A, 0x1245A, 1, 3),
+    (0x1245B, 0x1245B, 2, 3),
+    (0x1245C, 0x1245C, 5, 6),
+    (0x1245D, 0x1245D, 1, 3),
+    (0x1245E, 0x1245E, 2, 3),
+    (0x1245F, 0x1245F, 1, 8),
+    (0x12460, 0x12460, 1, 4),
+    (0x12461, 0x12461, 1, 6),
+    (0x12462, 0x12462, 1, 4),
+    (0x12463, 0x12463, 1, 4),
+    (0x12464, 0x12464, 1, 2),
+    (0x12465, 0x12465, 1, 3),
+    (0x12466, 0x12466, 2, 3),
+    (0x12467, 0x12467, 40, 1),
+    (0x12468, 0x12468, 50, 1),
+    (0x12469, 0x1246E, 4, 1),
+    (0x16A60, 0x16A69, 0, 1),
+    (0x16AC0, 0x16AC9, 0, 1),
+    (0x16B50, 0x16B59, 0, 1),
+    (0x16B5B, 0x16B5B, 10, 1),
+    (0x16B5C, 0x16B5C, 100, 1),
+    (0x16B5D, 0x16B5D, 10000, 1),
+    (0x16B5E, 0x16B5E, 1000000, 1),
+    (0x16B5F, 0x16B5F, 100000000, 1),
+    (0x16B60, 0x16B60, 10000000000, 1),
+    (0x16B61, 0x16B61, 1000000000000, 1),
+    (0x16E80, 0x16E93, 0, 1),
+    (0x16E94, 0x16E96, 1, 1),
+    (0x1D2E0, 0x1D2F3, 0, 1),
+    (0x1D360, 0x1D369, 1, 1),
+    (0x1D36A, 0x1D36A, 20, 1),
+    (0x1D36B, 0x1D36B, 30, 1),
+    (0x1D36C, 0x1D36C, 40, 1),
+    (0x1D36D, 0x1D36D, 50, 1),
+    (0x1D36E, 0x1D36E, 60, 1),
+    (0x1D36F, 0x1D36F, 70, 1),
+    (0x1D370, 0x1D370, 80, 1),
+    (0x1D371, 0x1D371, 90, 1),
+    (0x1D372, 0x1D376, 1, 1),
+    (0x1D377, 0x1D377, 1, 1),
+    (0x1D378, 0x1D378, 5, 1),
+    (0x1D7CE, 0x1D7D7, 0, 1),
+    (0x1D7D8, 0x1D7E1, 0, 1),
+    (0x1D7E2, 0x1D7EB, 0, 1),
+    (0x1D7EC, 0x1D7F5, 0, 1),
+    (0x1D7F6, 0x1D7FF, 0, 1),
+    (0x1E140, 0x1E149, 0, 1),
+    (0x1E2F0, 0x1E2F9, 0, 1),
+    (0x1E8C7, 0x1E8CF, 1, 1),
+    (0x1E950, 0x1E959, 0, 1),
+    (0x1EC71, 0x1EC7A, 1, 1),
+    (0x1EC7B, 0x1EC7B, 20, 1),
+    (0x1EC7C, 0x1EC7C, 30, 1),
+    (0x1EC7D, 0x1EC7D, 40, 1),
+    (0x1EC7E, 0x1EC7E, 50, 1),
+    (0x1EC7F, 0x1EC7F, 60, 1),
+    (0x1EC80, 0x1EC80, 70, 1),
+    (0x1EC81, 0x1EC81, 80, 1),
+    (0x1EC82, 0x1EC82, 90, 1),
+    (0x1EC83, 0x1EC83, 100, 1),
+    (0x1EC84, 0x1EC84, 200, 1),
+    (0x1EC85, 0x1EC85, 300, 1),
+    (0x1EC86, 0x1EC86, 400, 1),
+    (0x1EC87, 0x1EC87, 500, 1),
+    (0x1EC88, 0x1EC88, 600, 1),
+    (0x1EC89, 0x1EC89, 700, 1),
+    (0x1EC8A, 0x1EC8A, 800, 1),
+    (0x1EC8B, 0x1EC8B, 900, 1),
+    (0x1EC8C, 0x1EC8C, 1000, 1),
+    (0x1EC8D, 0x1EC8D, 2000, 1),
+    (0x1EC8E, 0x1EC8E, 3000, 1),
+    (0x1EC8F, 0x1EC8F, 4000, 1),
+    (0x1EC90, 0x1EC90, 5000, 1),
+    (0x1EC91, 0x1EC91, 6000, 1),
+    (0x1EC92, 0x1EC92, 7000, 1),
+    (0x1EC93, 0x1EC93, 8000, 1),
+    (0x1EC94, 0x1EC94, 9000, 1),
+    (0x1EC95, 0x1EC95, 10000, 1),
+    (0x1EC96, 0x1EC96, 20000, 1),
+    (0x1EC97, 0x1EC97, 30000, 1),
+    (0x1EC98, 0x1EC98, 40000, 1),
+    (0x1EC99, 0x1EC99, 50000, 1),
+    (0x1EC9A, 0x1EC9A, 60000, 1),
+    (0x1EC9B, 0x1EC9B, 70000, 1),
+    (0x1EC9C, 0x1EC9C, 80000, 1),
+    (0x1EC9D, 0x1EC9D, 90000, 1),
+    (0x1EC9E, 0x1EC9E, 100000, 1),
+    (0x1EC9F, 0x1EC9F, 200000, 1),
+    (0x1ECA0, 0x1ECA0, 100000, 1),
+    (0x1ECA1, 0x1ECA1, 10000000, 1),
+    (0x1ECA2, 0x1ECA2, 20000000, 1),
+    (0x1ECA3, 0x1ECAB, 1, 1),
+    (0x1ECAD, 0x1ECAD, 1, 4),
+    (0x1ECAE, 0x1ECAE, 1, 2),
+    (0x1ECAF, 0x1ECAF, 3, 4),
+    (0x1ECB1, 0x1ECB2, 1, 1),
+    (0x1ECB3, 0x1ECB3, 10000, 1),
+    (0x1ECB4, 0x1ECB4, 100000, 1),
+    (0x1ED01, 0x1ED0A, 1, 1),
+    (0x1ED0B, 0x1ED0B, 20, 1),
+    (0x1ED0C, 0x1ED0C, 30, 1),
+    (0x1ED0D, 0x1ED0D, 40, 1),
+    (0x1ED0E, 0x1ED0E, 50, 1),
+    (0x1ED0F, 0x1ED0F, 60, 1),
+    (0x1ED10, 0x1ED10, 70, 1),
+    (0x1ED11, 0x1ED11, 80, 1),
+    (0x1ED12, 0x1ED12, 90, 1),
+    (0x1ED13, 0x1ED13, 100, 1),
+    (0x1ED14, 0x1ED14, 200, 1),
+    (0x1ED15, 0x1ED15, 300, 1),
+    (0x1ED16, 0x1ED16, 400, 1),
+    (0x1ED17, 0x1ED17, 500, 1),
+    (0x1ED18, 0x1ED18, 600, 1),
+    (0x1ED19, 0x1ED19, 700, 1),
+    (0x1ED1A, 0x1ED1A, 800, 1),
+    (0x1ED1B, 0x1ED1B, 900, 1),
+    (0x1ED1C, 0x1ED1C, 1000, 1),
+    (0x1ED1D, 0x1ED1D, 2000, 1),
+    (0x1ED1E, 0x1ED1E, 3000, 1),
+    (0x1ED1F, 0x1ED1F, 4000, 1),
+    (0x1ED20, 0x1ED20, 5000, 1),
+    (0x1ED21, 0x1ED21, 6000, 1),
+    (0x1ED22, 0x1ED22, 7000, 1),
+    (0x1ED23, 0x1ED23, 8000, 1),
+    (0x1ED24, 0x1ED24, 9000, 1),
+    (0x1ED25, 0x1ED25, 10000, 1),
+    (0x1ED26, 0x1ED26, 20000, 1),
+    (0x1ED27, 0x1ED27, 30000, 1),
+    (0x1ED28, 0x1ED28, 40000, 1),
+    (0x1ED29, 0x1ED29, 50000, 1),
+    (0x1ED2A, 0x1ED2A, 60000, 1),
+    (0x1ED2B, 0x1ED2B, 70000, 1),
+    (0x1ED2C, 0x1ED2C, 80000, 1),
+    (0x1ED2D, 0x1ED2D, 90000, 1),
+    (0x1ED2F, 0x1ED37, 2, 1),
+    (0x1ED38, 0x1ED38, 400, 1),
+    (0x1ED39, 0x1ED39, 600, 1),
+    (0x1ED3A, 0x1ED3A, 2000, 1),
+    (0x1ED3B, 0x1ED3B, 10000, 1),
+    (0x1ED3C, 0x1ED3C, 1, 2),
+    (0x1ED3D, 0x1ED3D, 1, 6),
+    (0x1F100, 0x1F100, 0, 1),
+    (0x1F101, 0x1F10A, 0, 1),
+    (0x1F10B, 0x1F10B, 0, 1),
+    (0x1F10C, 0x1F10C, 0, 1),
+    (0x1FBF0, 0x1FBF9, 0, 1),
+    (0x20001, 0x20001, 7, 1),
+    (0x20064, 0x20064, 4, 1),
+    (0x200E2, 0x200E2, 4, 1),
+    (0x20121, 0x20121, 5, 1),
+    (0x2092A, 0x2092A, 1, 1),
+    (0x20983, 0x20983, 30, 1),
+    (0x2098C, 0x2098C, 40, 1),
+    (0x2099C, 0x2099C, 40, 1),
+    (0x20AEA, 0x20AEA, 6, 1),
+    (0x20AFD, 0x20AFD, 3, 1),
+    (0x20B19, 0x20B19, 3, 1),
+    (0x22390, 0x22390, 2, 1),
+    (0x22998, 0x22998, 3, 1),
+    (0x23B1B, 0x23B1B, 3, 1),
+    (0x2626D, 0x2626D, 4, 1),
+    (0x2F890, 0x2F890, 9, 1),
+];
+
+/// The Unicode numeric value of `chr`, e.g. `7.0` for `Ⅶ` or `0.5` for `½`.
+pub fn numeric_value(chr: char) -> Option<f64> {
+    let codepoint = chr as u32;
+    let index = NUMERIC_VALUES
+        .binary_search_by(|(first, last, ..)| {
+            if codepoint < *first {
+                std::cmp::Ordering::Greater
+            } else if *last < codepoint {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()?;
+    let (first, _, numerator, denominator) = NUMERIC_VALUES[index];
+
+    Some(numerator as f64 / denominator as f64 + (codepoint - first) as f64)
+}
+
+/// All characters whose numeric value is `value`.
+pub fn chars_with_value(value: f64) -> Vec<char> {
+    NUMERIC_VALUES
+        .iter()
+        .flat_map(|&(first, last, ..)| first..=last)
+        .filter_map(char::from_u32)
+        .filter(|&chr| {
+            numeric_value(chr).is_some_and(|numeric_value| {
+                (numeric_value - value).abs() <= 1e-9 * value.abs().max(1.0)
+            })
+        })
+        .collect()
+}
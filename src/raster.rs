@@ -0,0 +1,456 @@
+use ahash::AHashMap as HashMap;
+use std::borrow::Cow;
+
+/// A font from the font definitions, parsed for software rasterization.
+pub struct RasterFont {
+    /// The font's name in the font definitions.
+    pub name: String,
+    /// The scale tweak egui applies to this font.
+    pub scale: f32,
+    pub font: rusttype::Font<'static>,
+}
+
+/// Per-glyph metrics, in the units of the size passed to
+/// [`RasterFont::glyph_metrics()`], relative to the top left corner of
+/// the cell egui lays the glyph out in.
+pub struct GlyphMetrics {
+    pub advance: f32,
+    pub baseline: f32,
+    /// The ink bounding box; `None` for glyphs without an outline.
+    pub bounds: Option<egui::Rect>,
+}
+
+impl RasterFont {
+    pub fn has_glyph(&self, chr: char) -> bool {
+        0 != self.font.glyph(chr).id().0
+    }
+
+    /// The ascent and descent of the font at the size egui renders it with
+    /// when asked for `size`, i.e. including the scale tweak.
+    pub fn v_metrics(&self, size: f32) -> rusttype::VMetrics {
+        self.font
+            .v_metrics(rusttype::Scale::uniform(size * self.scale))
+    }
+
+    /// Returns the advance, baseline and ink bounding box of `chr` at `size`.
+    /// The result is meaningless if the font has no glyph for `chr`.
+    pub fn glyph_metrics(&self, chr: char, size: f32) -> GlyphMetrics {
+        let v_metrics = self.font.v_metrics(rusttype::Scale::uniform(size));
+        let y_offset = (self.scale - 1.0) * 0.5 * (v_metrics.ascent + v_metrics.descent);
+        let baseline = v_metrics.ascent + y_offset;
+
+        let glyph = self
+            .font
+            .glyph(chr)
+            .scaled(rusttype::Scale::uniform(size * self.scale));
+        let advance = glyph.h_metrics().advance_width;
+
+        // Relative to the pen origin, with y pointing down.
+        let bounds = glyph.exact_bounding_box().map(|bounds| {
+            egui::Rect::from_min_max(
+                egui::Pos2::new(bounds.min.x, baseline + bounds.min.y),
+                egui::Pos2::new(bounds.max.x, baseline + bounds.max.y),
+            )
+        });
+
+        GlyphMetrics {
+            advance,
+            baseline,
+            bounds,
+        }
+    }
+
+    /// Rasterizes `glyph`, a character or a glyph id, into an image that is
+    /// `size` pixels high and as wide as the glyph's advance, with the
+    /// baseline where egui would put it.
+    ///
+    /// Without `antialias` every pixel is either fully opaque or fully
+    /// transparent, which keeps pixel fonts and box drawing characters crisp.
+    pub fn rasterize(
+        &self,
+        glyph: impl rusttype::IntoGlyphId,
+        size: f32,
+        antialias: bool,
+    ) -> egui::ColorImage {
+        let v_metrics = self.font.v_metrics(rusttype::Scale::uniform(size));
+        // Same vertical correction egui applies to fonts with a scale tweak.
+        let y_offset = (self.scale - 1.0) * 0.5 * (v_metrics.ascent + v_metrics.descent);
+
+        let glyph = self
+            .font
+            .glyph(glyph)
+            .scaled(rusttype::Scale::uniform(size * self.scale));
+        let advance = glyph.h_metrics().advance_width;
+        let glyph = glyph.positioned(rusttype::point(0.0, v_metrics.ascent + y_offset));
+
+        let width = advance.ceil().max(1.0) as usize;
+        let height = (v_metrics.ascent - v_metrics.descent).ceil().max(1.0) as usize;
+
+        let mut image = egui::ColorImage::new([width, height], egui::Color32::TRANSPARENT);
+
+        if let Some(bounding_box) = glyph.pixel_bounding_box() {
+            glyph.draw(|x, y, coverage| {
+                let x = x as i32 + bounding_box.min.x;
+                let y = y as i32 + bounding_box.min.y;
+
+                if (0..width as i32).contains(&x) && (0..height as i32).contains(&y) {
+                    let alpha = if antialias {
+                        (coverage * 255.0).round() as u8
+                    } else if 0.5 <= coverage {
+                        255
+                    } else {
+                        0
+                    };
+                    image.pixels[y as usize * width + x as usize] =
+                        egui::Color32::from_white_alpha(alpha);
+                }
+            });
+        }
+
+        image
+    }
+}
+
+/// Rasterizes glyphs with `rusttype`, using the same fonts (and fallback
+/// order) egui uses for a given font family.
+#[derive(Default)]
+pub struct GlyphRasterizer {
+    fonts: Vec<RasterFont>,
+}
+
+impl GlyphRasterizer {
+    pub fn new(definitions: &egui::FontDefinitions, family: &egui::FontFamily) -> Self {
+        Self {
+            fonts: definitions
+                .families
+                .get(family)
+                .into_iter()
+                .flatten()
+                .filter_map(|name| {
+                    let data = definitions.font_data.get(name)?;
+                    let font = match &data.font {
+                        Cow::Borrowed(bytes) => {
+                            rusttype::Font::try_from_bytes_and_index(bytes, data.index)
+                        }
+                        Cow::Owned(bytes) => {
+                            rusttype::Font::try_from_vec_and_index(bytes.clone(), data.index)
+                        }
+                    }?;
+
+                    Some(RasterFont {
+                        name: name.clone(),
+                        scale: data.tweak.scale,
+                        font,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// The fonts in fallback order.
+    pub fn fonts(&self) -> &[RasterFont] {
+        &self.fonts
+    }
+
+    /// The first font, in fallback order, that has a glyph for `chr`.
+    pub fn font_for_char(&self, chr: char) -> Option<&RasterFont> {
+        self.fonts
+            .iter()
+            .find(|raster_font| raster_font.has_glyph(chr))
+    }
+
+    /// Returns the advance, baseline and ink bounding box of `chr` at `size`.
+    pub fn glyph_metrics(&self, chr: char, size: f32) -> Option<GlyphMetrics> {
+        Some(self.font_for_char(chr)?.glyph_metrics(chr, size))
+    }
+
+    /// Rasterizes `chr` with the first font that has it. See
+    /// [`RasterFont::rasterize()`].
+    pub fn rasterize(&self, chr: char, size: f32, antialias: bool) -> Option<egui::ColorImage> {
+        Some(self.font_for_char(chr)?.rasterize(chr, size, antialias))
+    }
+
+    /// Renders `chr` in every font that has it, side by side on a shared
+    /// baseline and labeled with the font's name, black on white.
+    ///
+    /// Returns `None` if no font has the glyph.
+    pub fn contact_sheet(&self, chr: char, size: f32) -> Option<image::RgbaImage> {
+        const PADDING: usize = 8;
+        let label_size = (0.25 * size).max(12.0);
+
+        let columns = self
+            .fonts
+            .iter()
+            .filter(|raster_font| raster_font.has_glyph(chr))
+            .map(|raster_font| {
+                (
+                    raster_font.rasterize(chr, size, true),
+                    raster_font.glyph_metrics(chr, size).baseline.round() as usize,
+                    self.rasterize_text(&raster_font.name, label_size),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if columns.is_empty() {
+            return None;
+        }
+
+        let ascent = columns
+            .iter()
+            .map(|(_, baseline, _)| *baseline)
+            .max()
+            .unwrap_or_default();
+        let descent = columns
+            .iter()
+            .map(|(glyph, baseline, _)| glyph.size[1].saturating_sub(*baseline))
+            .max()
+            .unwrap_or_default();
+        let label_height = columns
+            .iter()
+            .map(|(_, _, label)| label.size[1])
+            .max()
+            .unwrap_or_default();
+        let column_widths = columns
+            .iter()
+            .map(|(glyph, _, label)| glyph.size[0].max(label.size[0]) + 2 * PADDING)
+            .collect::<Vec<_>>();
+
+        let mut sheet = image::RgbaImage::from_pixel(
+            column_widths.iter().sum::<usize>() as _,
+            (ascent + descent + label_height + 3 * PADDING) as _,
+            image::Rgba([255, 255, 255, 255]),
+        );
+
+        let mut left = 0;
+        for ((glyph, baseline, label), column_width) in columns.iter().zip(column_widths) {
+            draw_coverage(
+                &mut sheet,
+                glyph,
+                left + (column_width - glyph.size[0]) / 2,
+                PADDING + ascent - baseline,
+            );
+            draw_coverage(
+                &mut sheet,
+                label,
+                left + (column_width - label.size[0]) / 2,
+                2 * PADDING + ascent + descent,
+            );
+
+            left += column_width;
+        }
+
+        Some(sheet)
+    }
+
+    /// Rasterizes `text` on a single line, each character with the first
+    /// font that has it.
+    fn rasterize_text(&self, text: &str, size: f32) -> egui::ColorImage {
+        let glyphs = text
+            .chars()
+            .filter_map(|chr| self.rasterize(chr, size, true))
+            .collect::<Vec<_>>();
+
+        let mut image = egui::ColorImage::new(
+            [
+                glyphs
+                    .iter()
+                    .map(|glyph| glyph.size[0])
+                    .sum::<usize>()
+                    .max(1),
+                glyphs.iter().map(|glyph| glyph.size[1]).max().unwrap_or(1),
+            ],
+            egui::Color32::TRANSPARENT,
+        );
+
+        let mut left = 0;
+        for glyph in glyphs {
+            for (y, row) in glyph.pixels.chunks(glyph.size[0]).enumerate() {
+                let start = y * image.size[0] + left;
+                image.pixels[start..start + row.len()].copy_from_slice(row);
+            }
+            left += glyph.size[0];
+        }
+
+        image
+    }
+
+    /// Like [`Self::rasterize_text()`] but shrunk to at most `max_width`.
+    fn rasterize_text_to_fit(&self, text: &str, size: f32, max_width: usize) -> egui::ColorImage {
+        let image = self.rasterize_text(text, size);
+
+        if image.size[0] <= max_width {
+            image
+        } else {
+            self.rasterize_text(text, size * max_width as f32 / image.size[0] as f32)
+        }
+    }
+}
+
+/// Lays out a card about `chr` to share, like the inspector does: the glyph
+/// big, rendered with `glyph_rasterizer`, below it its `name` and `rows` of
+/// labels and values, rendered with `text_rasterizer`. Black on white.
+///
+/// Returns `None` if `glyph_rasterizer` has no glyph for `chr`.
+pub fn info_card(
+    glyph_rasterizer: &GlyphRasterizer,
+    text_rasterizer: &GlyphRasterizer,
+    chr: char,
+    name: &str,
+    rows: &[(&str, String)],
+) -> Option<image::RgbaImage> {
+    const WIDTH: usize = 640;
+    const HEIGHT: usize = 800;
+    const PADDING: usize = 32;
+    const GLYPH_SIZE: f32 = 320.0;
+    const NAME_SIZE: f32 = 32.0;
+    const ROW_SIZE: f32 = 22.0;
+
+    let font = glyph_rasterizer.font_for_char(chr)?;
+    let max_width = WIDTH - 2 * PADDING;
+
+    let mut card =
+        image::RgbaImage::from_pixel(WIDTH as _, HEIGHT as _, image::Rgba([255, 255, 255, 255]));
+
+    // Shrink glyphs wider than the card, e.g. ligatures like ﷽.
+    let advance = font.glyph_metrics(chr, GLYPH_SIZE).advance;
+    let glyph = font.rasterize(
+        chr,
+        GLYPH_SIZE.min(GLYPH_SIZE * max_width as f32 / advance),
+        true,
+    );
+    draw_coverage(
+        &mut card,
+        &glyph,
+        WIDTH.saturating_sub(glyph.size[0]) / 2,
+        PADDING,
+    );
+
+    let mut top = 2 * PADDING + glyph.size[1];
+
+    for line in textwrap::wrap(name, 32) {
+        let line = text_rasterizer.rasterize_text_to_fit(&line, NAME_SIZE, max_width);
+        draw_coverage(&mut card, &line, (WIDTH - line.size[0]) / 2, top);
+        top += line.size[1];
+    }
+
+    top += PADDING;
+
+    // Labels end, values start a bit off the middle.
+    let column_width = (max_width - PADDING) / 2;
+
+    for (label, value) in rows {
+        let label = text_rasterizer.rasterize_text_to_fit(label, ROW_SIZE, column_width);
+        let value = text_rasterizer.rasterize_text_to_fit(value, ROW_SIZE, column_width);
+
+        draw_coverage(
+            &mut card,
+            &label,
+            (WIDTH - PADDING) / 2 - label.size[0].min(column_width),
+            top,
+        );
+        draw_coverage(&mut card, &value, (WIDTH + PADDING) / 2, top);
+
+        top += label.size[1].max(value.size[1]) + PADDING / 4;
+    }
+
+    Some(card)
+}
+
+/// Blends the coverage in the alpha channel of `source` onto `target` in
+/// black, with its top left corner at `left`, `top`.
+fn draw_coverage(
+    target: &mut image::RgbaImage,
+    source: &egui::ColorImage,
+    left: usize,
+    top: usize,
+) {
+    for (i, pixel) in source.pixels.iter().enumerate() {
+        let (x, y) = (
+            (left + i % source.size[0]) as u32,
+            (top + i / source.size[0]) as u32,
+        );
+
+        if x < target.width() && y < target.height() {
+            let image::Rgba([value, ..]) = *target.get_pixel(x, y);
+            let value = value.saturating_sub(pixel.a());
+            target.put_pixel(x, y, image::Rgba([value, value, value, 255]));
+        }
+    }
+}
+
+/// Caches textures of glyphs rasterized without antialiasing.
+#[derive(Default)]
+pub struct CrispGlyphCache {
+    rasterizer: GlyphRasterizer,
+    textures: HashMap<(char, u32), Option<egui::TextureHandle>>,
+}
+
+impl CrispGlyphCache {
+    // Panel resizing creates a new texture for every preview size so we
+    // start over once the cache grows beyond this.
+    const MAX_LEN: usize = 4096;
+
+    pub fn new(rasterizer: GlyphRasterizer) -> Self {
+        Self {
+            rasterizer,
+            textures: Default::default(),
+        }
+    }
+
+    pub fn rasterizer(&self) -> &GlyphRasterizer {
+        &self.rasterizer
+    }
+
+    /// Returns the texture for `chr` rendered at `size` points or `None` if
+    /// none of the fonts has a glyph for it.
+    pub fn texture(
+        &mut self,
+        ctx: &egui::Context,
+        chr: char,
+        size: f32,
+    ) -> Option<egui::TextureHandle> {
+        let size_in_pixels = (size * ctx.pixels_per_point()).round() as u32;
+
+        if Self::MAX_LEN <= self.textures.len() {
+            self.textures.clear();
+        }
+
+        self.textures
+            .entry((chr, size_in_pixels))
+            .or_insert_with(|| {
+                self.rasterizer
+                    .rasterize(chr, size_in_pixels as _, false)
+                    .map(|image| {
+                        ctx.load_texture(
+                            format!("crisp-glyph-{}-{size_in_pixels}", chr as u32),
+                            image,
+                            egui::TextureOptions::NEAREST,
+                        )
+                    })
+            })
+            .clone()
+    }
+}
+
+/// Paints a texture from [`CrispGlyphCache`] with pixels mapped 1:1 to
+/// screen pixels.
+pub fn paint_crisp_glyph(
+    painter: &egui::Painter,
+    texture: &egui::TextureHandle,
+    anchor: egui::Pos2,
+    align: egui::Align2,
+    color: egui::Color32,
+) {
+    let pixels_per_point = painter.ctx().pixels_per_point();
+    let size = texture.size_vec2() / pixels_per_point;
+    // Snap to the pixel grid or NEAREST filtering will still smear.
+    let rect = align.anchor_rect(egui::Rect::from_min_size(anchor, size));
+    let min = (rect.min.to_vec2() * pixels_per_point).round() / pixels_per_point;
+
+    painter.image(
+        texture.id(),
+        egui::Rect::from_min_size(min.to_pos2(), size),
+        egui::Rect::from_min_max(egui::Pos2::ZERO, egui::Pos2::new(1.0, 1.0)),
+        color,
+    );
+}
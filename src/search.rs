@@ -0,0 +1,1054 @@
+use crate::numeric_value;
+use ahash::AHashSet as HashSet;
+use finl_unicode::categories::CharacterCategories;
+use std::{collections::BTreeMap, ops::RangeInclusive};
+use unicode_blocks as ub;
+use unicode_linebreak::{break_property, BreakClass};
+use unicode_normalization::{char::canonical_combining_class, UnicodeNormalization};
+use unicode_script::{Script, UnicodeScript};
+
+/// The line breaking classes of UAX #14 by their abbreviation, for `lb:`.
+const LINE_BREAK_CLASSES: [(&str, BreakClass); 43] = [
+    ("BK", BreakClass::Mandatory),
+    ("CR", BreakClass::CarriageReturn),
+    ("LF", BreakClass::LineFeed),
+    ("CM", BreakClass::CombiningMark),
+    ("NL", BreakClass::NextLine),
+    ("SG", BreakClass::Surrogate),
+    ("WJ", BreakClass::WordJoiner),
+    ("ZW", BreakClass::ZeroWidthSpace),
+    ("GL", BreakClass::NonBreakingGlue),
+    ("SP", BreakClass::Space),
+    ("ZWJ", BreakClass::ZeroWidthJoiner),
+    ("B2", BreakClass::BeforeAndAfter),
+    ("BA", BreakClass::After),
+    ("BB", BreakClass::Before),
+    ("HY", BreakClass::Hyphen),
+    ("CB", BreakClass::Contingent),
+    ("CL", BreakClass::ClosePunctuation),
+    ("CP", BreakClass::CloseParenthesis),
+    ("EX", BreakClass::Exclamation),
+    ("IN", BreakClass::Inseparable),
+    ("NS", BreakClass::NonStarter),
+    ("OP", BreakClass::OpenPunctuation),
+    ("QU", BreakClass::Quotation),
+    ("IS", BreakClass::InfixSeparator),
+    ("NU", BreakClass::Numeric),
+    ("PO", BreakClass::Postfix),
+    ("PR", BreakClass::Prefix),
+    ("SY", BreakClass::Symbol),
+    ("AI", BreakClass::Ambiguous),
+    ("AL", BreakClass::Alphabetic),
+    ("CJ", BreakClass::ConditionalJapaneseStarter),
+    ("EB", BreakClass::EmojiBase),
+    ("EM", BreakClass::EmojiModifier),
+    ("H2", BreakClass::HangulLvSyllable),
+    ("H3", BreakClass::HangulLvtSyllable),
+    ("HL", BreakClass::HebrewLetter),
+    ("ID", BreakClass::Ideographic),
+    ("JL", BreakClass::HangulLJamo),
+    ("JV", BreakClass::HangulVJamo),
+    ("JT", BreakClass::HangulTJamo),
+    ("RI", BreakClass::RegionalIndicator),
+    ("SA", BreakClass::ComplexContext),
+    ("XX", BreakClass::Unknown),
+];
+
+/// A term standing for all characters with a property:
+///
+/// * `ccc:` followed by a canonical combining class, e.g. `ccc:230` for
+///   marks above the base.
+/// * `script:` followed by a script's name or ISO 15924 code, e.g.
+///   `script:Greek` or `script:Grek`, for all characters used by that
+///   script, i.e. with it in their Script_Extensions.
+/// * `lb:` followed by the abbreviation of a line breaking class, see
+///   [`LINE_BREAK_CLASSES`], e.g. `lb:BK` or `lb:gl`.
+///
+/// Unlike escape sequences these are matched against the characters
+/// searched rather than resolved to all of Unicode up front.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PropertyPattern {
+    CombiningClass(u8),
+    Script(Script),
+    LineBreak(BreakClass),
+}
+
+impl PropertyPattern {
+    pub fn matches(self, chr: char) -> bool {
+        match self {
+            PropertyPattern::CombiningClass(class) => class == canonical_combining_class(chr),
+            PropertyPattern::Script(script) => chr.script_extension().iter().any(|s| script == s),
+            PropertyPattern::LineBreak(class) => class == break_property(chr as u32),
+        }
+    }
+}
+
+/// The contents of the search field, preprocessed once per edit.
+#[derive(Clone, Debug, Default)]
+pub struct SearchParams {
+    pub text: String,
+    pub split_text_lower: Vec<String>,
+    pub case_sensitive: bool,
+    pub search_name: bool,
+    /// Match e.g. `cafe` with `café` and vice versa. Never for a case
+    /// sensitive search, which matches exactly what was typed.
+    pub ignore_diacritics: bool,
+    /// Also match deprecated characters and those in the Tags block.
+    pub include_deprecated: bool,
+    /// Match names regardless of English word forms, e.g. `arrows` with
+    /// `arrow`. See [`stem()`].
+    pub stem_words: bool,
+    /// The terms [`stem()`]med, if `stem_words`. Only used to match stemmed
+    /// names; everything else uses `split_text_lower`.
+    pub stemmed_terms: Vec<String>,
+    /// Also match loosely: names by the block a character is in or by the
+    /// initials of their words, and characters by what they are confusable
+    /// with.
+    pub fuzzy: bool,
+    /// Match names against this regular expression instead of the terms,
+    /// see [`SearchParams::set_regex()`]. An error if it doesn't compile.
+    pub regex: Option<Result<regex::Regex, regex::Error>>,
+    /// Characters given as escape sequences, e.g. `\u{1F600}`, or by a
+    /// pattern like `num:7`.
+    pub escaped_chars: Vec<char>,
+    /// Terms like `ccc:230` that stand for characters with a property.
+    pub property_patterns: Vec<PropertyPattern>,
+    /// Every term of the query is an escape sequence or pattern.
+    pub only_special_patterns: bool,
+    /// The character whose Unicode name is the query, if any.
+    pub full_name_match: Option<char>,
+    /// Lower case terms no matching name may contain, given with a leading
+    /// `-`, e.g. `capital` for `latin -capital`.
+    pub excluded_terms: Vec<String>,
+    /// Characters never to match, e.g. those of a category the user
+    /// excluded.
+    pub excluded_chars: HashSet<char>,
+}
+
+impl SearchParams {
+    pub fn new(
+        text: &str,
+        case_sensitive: bool,
+        search_name: bool,
+        ignore_diacritics: bool,
+        include_deprecated: bool,
+        stem_words: bool,
+        fuzzy: bool,
+    ) -> Self {
+        // A lone `-` is a hyphen to search for.
+        let is_excluded = |term: &&str| 1 < term.len() && term.starts_with('-');
+        let excluded_terms = text
+            .split(' ')
+            .filter(is_excluded)
+            .map(|term| term[1..].to_lowercase())
+            .map(|term| match ignore_diacritics && !case_sensitive {
+                true => strip_diacritics(&term),
+                false => term,
+            })
+            .collect();
+        let text = &text
+            .split(' ')
+            .filter(|term| !is_excluded(term))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let escaped_chars = SearchEngine::search_special_patterns(text);
+        let property_patterns = text
+            .split(' ')
+            .filter_map(SearchEngine::decode_property_pattern)
+            .collect();
+        let only_special_patterns = text
+            .split(' ')
+            .filter(|term| !term.is_empty())
+            .all(SearchEngine::is_special_pattern);
+
+        // Shortcodes no emoji has are searched as names, e.g. `:red_car:`
+        // as `red car`.
+        let text = &text
+            .split(' ')
+            .map(|term| match SearchEngine::strip_shortcode_colons(term) {
+                Some(shortcode) if SearchEngine::decode_shortcode(term).is_none() => {
+                    shortcode.replace('_', " ")
+                }
+                _ => term.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let ignore_diacritics = ignore_diacritics && !case_sensitive;
+        let text = if ignore_diacritics {
+            strip_diacritics(text)
+        } else {
+            text.to_string()
+        };
+
+        let split_text_lower = text
+            .split(' ')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_lowercase())
+            .collect::<Vec<_>>();
+        let stemmed_terms = match stem_words {
+            true => split_text_lower.iter().map(|term| stem(term)).collect(),
+            false => Vec::new(),
+        };
+
+        Self {
+            full_name_match: SearchEngine::search_full_name(&split_text_lower),
+            split_text_lower,
+            text: if case_sensitive {
+                text
+            } else {
+                text.to_lowercase()
+            },
+            case_sensitive,
+            search_name,
+            ignore_diacritics,
+            include_deprecated,
+            stem_words,
+            stemmed_terms,
+            fuzzy,
+            regex: None,
+            escaped_chars,
+            property_patterns,
+            only_special_patterns,
+            excluded_terms,
+            excluded_chars: HashSet::new(),
+        }
+    }
+
+    /// Matches names against `pattern`, e.g. `^latin.*letter.*a$`, instead
+    /// of the terms. Case is ignored unless `case_sensitive`, in which case
+    /// names are matched in upper case, as the standard writes them.
+    pub fn set_regex(&mut self, pattern: &str) {
+        self.regex = Some(
+            regex::RegexBuilder::new(pattern)
+                .case_insensitive(!self.case_sensitive)
+                .build(),
+        );
+        // Nothing in a pattern is an escape sequence, excluded term or name.
+        self.escaped_chars.clear();
+        self.property_patterns.clear();
+        self.excluded_terms.clear();
+        self.only_special_patterns = false;
+        self.full_name_match = None;
+    }
+}
+
+pub struct SearchEngine;
+
+impl SearchEngine {
+    /// Returns all entries of `full_cache` matching `params`.
+    pub fn search(
+        full_cache: &BTreeMap<char, String>,
+        params: &SearchParams,
+    ) -> BTreeMap<char, String> {
+        // Rather than nothing while a pattern is being typed.
+        if let Some(Err(_)) = params.regex {
+            return full_cache.clone();
+        }
+
+        let escaped = params
+            .escaped_chars
+            .iter()
+            .filter_map(|chr| full_cache.get_key_value(chr))
+            .chain(full_cache.iter().filter(|(&chr, _)| {
+                params
+                    .property_patterns
+                    .iter()
+                    .any(|pattern| pattern.matches(chr))
+            }))
+            .filter(|(&chr, name)| !Self::is_excluded(chr, name, params))
+            .map(|(&chr, name)| (chr, name.clone()));
+
+        // A query made up of escape sequences and patterns only means exactly
+        // these.
+        if params.only_special_patterns && !params.split_text_lower.is_empty() {
+            return escaped.collect();
+        }
+
+        full_cache
+            .iter()
+            .filter(|(&chr, name)| !Self::is_excluded(chr, name, params))
+            // Excluded terms alone narrow everything.
+            .filter(|(&chr, name)| {
+                (params.split_text_lower.is_empty() && !params.excluded_terms.is_empty())
+                    || Self::apply_search_filters(chr, name, params)
+            })
+            .map(|(&chr, name)| (chr, name.clone()))
+            .chain(escaped)
+            .collect()
+    }
+
+    /// Whether `chr` named `name` is left out of the results, however it
+    /// was found.
+    fn is_excluded(chr: char, name: &str, params: &SearchParams) -> bool {
+        (!params.include_deprecated && is_deprecated_or_tag(chr))
+            || params.excluded_chars.contains(&chr)
+            || Self::has_excluded_term(name, params)
+    }
+
+    fn has_excluded_term(name: &str, params: &SearchParams) -> bool {
+        if params.excluded_terms.is_empty() {
+            return false;
+        }
+
+        let name = if params.ignore_diacritics && !name.is_ascii() {
+            strip_diacritics(name).into()
+        } else {
+            std::borrow::Cow::Borrowed(name)
+        };
+
+        params
+            .excluded_terms
+            .iter()
+            .any(|term| name.contains(term.as_str()))
+    }
+
+    /// How well a search result, `chr` named `name`, fits `params`; higher is
+    /// better. In tiers, each worth one point:
+    ///
+    /// 1. A name only the initials of whose words match, see
+    ///    [`Self::abbreviation_match()`].
+    /// 2. Anything else [`Self::search()`] finds, e.g. by block name.
+    /// 3. A name containing every term.
+    /// 4. A name with every term at the start of one of its words.
+    /// 5. A name that is the query.
+    /// 6. The character itself, typed or given by its codepoint.
+    /// 7. The character whose name is the query, see
+    ///    [`Self::search_full_name()`].
+    ///
+    /// Within a tier, names closer in length to the query rank higher.
+    pub fn relevance(chr: char, name: &str, params: &SearchParams) -> f32 {
+        let name = if params.ignore_diacritics && !name.is_ascii() {
+            strip_diacritics(name)
+        } else {
+            name.to_string()
+        };
+        let terms = &params.split_text_lower;
+        let query_len = terms.iter().map(|term| term.len()).sum::<usize>() + terms.len();
+
+        let tier = if params.full_name_match == Some(chr) {
+            7.0
+        } else if params.escaped_chars.contains(&chr)
+            || params
+                .property_patterns
+                .iter()
+                .any(|pattern| pattern.matches(chr))
+            || (!params.search_name && {
+                let mut lower = chr.to_lowercase();
+                match params.case_sensitive {
+                    true => params.text.contains(chr),
+                    false => lower.all(|lower| params.text.contains(lower)),
+                }
+            })
+        {
+            6.0
+        } else if params.regex.is_none() && name == terms.join(" ") {
+            5.0
+        } else if params.regex.is_none()
+            && !terms.is_empty()
+            && terms.iter().all(|term| {
+                name.split([' ', '-'])
+                    .any(|word| word.starts_with(term.as_str()))
+            })
+        {
+            4.0
+        } else if params.regex.is_none()
+            && !terms.is_empty()
+            && terms.iter().all(|term| name.contains(term.as_str()))
+        {
+            3.0
+        } else if params.search_name
+            && params.fuzzy
+            && params.regex.is_none()
+            && terms.iter().all(|term| !name.contains(term.as_str()))
+            && terms
+                .iter()
+                .any(|term| Self::abbreviation_match(&name, term))
+        {
+            1.0
+        } else {
+            2.0
+        };
+
+        tier + query_len.min(name.len()) as f32 / name.len().max(1) as f32 * 0.99
+    }
+
+    /// Returns the character whose Unicode name is made of `terms`, ignoring
+    /// case, e.g. `α` for `greek small letter alpha`. It ranks above all
+    /// other results.
+    ///
+    /// Single words are ignored as many names, e.g. `cloud`, are also part
+    /// of others a search for them should find.
+    fn search_full_name(terms: &[String]) -> Option<char> {
+        if terms.len() < 2 {
+            return None;
+        }
+
+        unicode_names2::character(&terms.join(" "))
+    }
+
+    /// Decodes the terms of `text` that are a codepoint or a character
+    /// escape sequence as found in source code:
+    ///
+    /// * Unicode: `U+1F600`
+    /// * Rust: `\u{1F600}`, `\x41`
+    /// * C & co.: `0x1F600`, `\u00E4`, `\U0001F600` and UTF-16 surrogate
+    ///   pairs like `\uD83D\uDE00`
+    /// * URL-style: `%u00E4`
+    /// * HTML: `&#x1F600;`, `&#128512;`
+    ///
+    /// Hex digits may be upper or lower case. A query made up of these only
+    /// finds exactly the characters they stand for.
+    ///
+    /// A bare term of four to six hex digits with at least one decimal digit,
+    /// e.g. `1f600` or `2023`, also finds the character with that codepoint,
+    /// in addition to what the term finds as text. Shorter bare terms are
+    /// never codepoints, as e.g. `41` is as likely meant as decimal or as
+    /// text; `41` searches for text and `U+41` finds `A`.
+    ///
+    /// Two codepoints or escape sequences joined by `..` or `..=`, e.g.
+    /// `U+0041..U+005A` or `0x41..=0x5A`, stand for all characters in between,
+    /// both included, in whichever order they are given.
+    ///
+    /// An emoji shortcode as used by GitHub or Slack, e.g. `:grinning:`,
+    /// stands for the emoji or, for a sequence like `:family_man_girl:`, its
+    /// characters.
+    ///
+    /// A term `num:` followed by a number or fraction, e.g. `num:7` or
+    /// `num:1/2`, stands for all characters with that numeric value, e.g.
+    /// `Ⅶ`, `⑦` and `七` or `½`.
+    ///
+    /// Terms standing for characters with other properties are a
+    /// [`PropertyPattern`] instead.
+    pub fn search_special_patterns(text: &str) -> Vec<char> {
+        text.split(' ')
+            .filter(|term| !term.is_empty())
+            .flat_map(|term| {
+                match Self::decode_escape(term).or_else(|| Self::decode_bare_hex(term)) {
+                    Some(chr) => vec![chr],
+                    None => Self::decode_codepoint_range(term)
+                        .map(|range| range.collect())
+                        .or_else(|| Self::decode_shortcode(term))
+                        .or_else(|| {
+                            Self::decode_numeric_value(term).map(numeric_value::chars_with_value)
+                        })
+                        .unwrap_or_default(),
+                }
+            })
+            .collect()
+    }
+
+    fn decode_bare_hex(term: &str) -> Option<char> {
+        if (4..=6).contains(&term.len())
+            && term.chars().all(|c| c.is_ascii_hexdigit())
+            && term.chars().any(|c| c.is_ascii_digit())
+        {
+            char::from_u32(u32::from_str_radix(term, 16).ok()?)
+        } else {
+            None
+        }
+    }
+
+    fn is_special_pattern(term: &str) -> bool {
+        Self::decode_escape(term).is_some()
+            || Self::decode_codepoint_range(term).is_some()
+            || Self::decode_shortcode(term).is_some()
+            || Self::decode_numeric_value(term).is_some()
+            || Self::decode_property_pattern(term).is_some()
+    }
+
+    fn decode_shortcode(term: &str) -> Option<Vec<char>> {
+        let emoji = emojis::get_by_shortcode(Self::strip_shortcode_colons(term)?)?;
+
+        Some(
+            emoji
+                .as_str()
+                .chars()
+                // Joiners and the emoji presentation selector.
+                .filter(|&chr| '\u{200D}' != chr && '\u{FE0F}' != chr)
+                .collect(),
+        )
+    }
+
+    /// `grinning` for `:grinning:`.
+    fn strip_shortcode_colons(term: &str) -> Option<&str> {
+        term.strip_prefix(':')?
+            .strip_suffix(':')
+            .filter(|shortcode| !shortcode.is_empty())
+    }
+
+    fn decode_codepoint_range(term: &str) -> Option<RangeInclusive<char>> {
+        let (first, last) = term.split_once("..")?;
+        let last = last.strip_prefix('=').unwrap_or(last);
+
+        let (first, last) = (Self::decode_escape(first)?, Self::decode_escape(last)?);
+
+        Some(first.min(last)..=first.max(last))
+    }
+
+    fn decode_property_pattern(term: &str) -> Option<PropertyPattern> {
+        Self::decode_combining_class(term)
+            .map(PropertyPattern::CombiningClass)
+            .or_else(|| Self::decode_script(term).map(PropertyPattern::Script))
+            .or_else(|| Self::decode_line_break_class(term).map(PropertyPattern::LineBreak))
+    }
+
+    fn decode_script(term: &str) -> Option<Script> {
+        let name = term
+            .strip_prefix("script:")
+            .or_else(|| term.strip_prefix("SCRIPT:"))?;
+
+        Script::from_full_name(name).or_else(|| Script::from_short_name(name))
+    }
+
+    fn decode_line_break_class(term: &str) -> Option<BreakClass> {
+        let abbreviation = term
+            .strip_prefix("lb:")
+            .or_else(|| term.strip_prefix("LB:"))?;
+
+        LINE_BREAK_CLASSES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(abbreviation))
+            .map(|(_, class)| *class)
+    }
+
+    fn decode_combining_class(term: &str) -> Option<u8> {
+        term.strip_prefix("ccc:")
+            .or_else(|| term.strip_prefix("CCC:"))?
+            .parse()
+            .ok()
+    }
+
+    fn decode_numeric_value(term: &str) -> Option<f64> {
+        let value = term
+            .strip_prefix("num:")
+            .or_else(|| term.strip_prefix("NUM:"))?;
+
+        let value = match value.split_once('/') {
+            Some((numerator, denominator)) => {
+                numerator.parse::<f64>().ok()? / denominator.parse::<f64>().ok()?
+            }
+            None => value.parse().ok()?,
+        };
+
+        value.is_finite().then_some(value)
+    }
+
+    fn decode_escape(term: &str) -> Option<char> {
+        let hex = |digits: &str, len: std::ops::RangeInclusive<usize>| {
+            if len.contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                u32::from_str_radix(digits, 16).ok()
+            } else {
+                None
+            }
+        };
+
+        if let Some(digits) = term
+            .strip_prefix("\\u{")
+            .and_then(|rest| rest.strip_suffix('}'))
+        {
+            char::from_u32(hex(digits, 1..=6)?)
+        } else if let Some(digits) = term
+            .strip_prefix("U+")
+            .or_else(|| term.strip_prefix("u+"))
+            .or_else(|| term.strip_prefix("0x"))
+            .or_else(|| term.strip_prefix("0X"))
+        {
+            char::from_u32(hex(digits, 1..=6)?)
+        } else if let Some(digits) = term
+            .strip_prefix("&#x")
+            .or_else(|| term.strip_prefix("&#X"))
+            .and_then(|rest| rest.strip_suffix(';'))
+        {
+            char::from_u32(hex(digits, 1..=6)?)
+        } else if let Some(digits) = term
+            .strip_prefix("&#")
+            .and_then(|rest| rest.strip_suffix(';'))
+        {
+            if (1..=7).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit()) {
+                char::from_u32(digits.parse().ok()?)
+            } else {
+                None
+            }
+        } else if let Some(digits) = term.strip_prefix("\\x") {
+            char::from_u32(hex(digits, 2..=2)?)
+        } else if let Some(digits) = term.strip_prefix("\\U") {
+            char::from_u32(hex(digits, 8..=8)?)
+        } else if let Some(digits) = term.strip_prefix("%u") {
+            char::from_u32(hex(digits, 4..=4)?)
+        } else if let Some(units) = term.strip_prefix("\\u") {
+            let units = units
+                .split("\\u")
+                .map(|digits| hex(digits, 4..=4).map(|unit| unit as u16))
+                .collect::<Option<Vec<_>>>()?;
+
+            let mut chars = char::decode_utf16(units);
+            match (chars.next(), chars.next()) {
+                (Some(Ok(chr)), None) => Some(chr),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    fn apply_search_filters(original_chr: char, name: &str, params: &SearchParams) -> bool {
+        let chr = match params.case_sensitive {
+            true => original_chr,
+            false => {
+                let lower_case = unicode_case_mapping::to_lowercase(original_chr);
+                match lower_case[0] {
+                    0 => original_chr,
+                    _ => char::from_u32(lower_case[0]).unwrap(),
+                }
+            }
+        };
+
+        // Unicode names are ASCII but our own names for special characters
+        // may not be.
+        let name = if params.ignore_diacritics && !name.is_ascii() {
+            strip_diacritics(name).into()
+        } else {
+            std::borrow::Cow::Borrowed(name)
+        };
+
+        if let Some(Ok(regex)) = &params.regex {
+            return if params.case_sensitive {
+                regex.is_match(&name.to_uppercase())
+            } else {
+                regex.is_match(&name)
+            };
+        }
+
+        params.full_name_match == Some(original_chr)
+            || (params.search_name
+                && params
+                    .split_text_lower
+                    .iter()
+                    .any(|text| name.contains(text)))
+            || (params.search_name && params.stem_words && {
+                let name = stem_words(&name);
+                params
+                    .stemmed_terms
+                    .iter()
+                    .any(|text| name.contains(text))
+            })
+            || (!params.search_name && params.text.contains(&chr.to_string()))
+            || (!params.search_name
+                && params.ignore_diacritics
+                && params.text.contains(&strip_diacritics(&chr.to_string())))
+            // A secondary signal, e.g. "cjk" for all characters in CJK blocks.
+            || (params.search_name
+                && params.fuzzy
+                && ub::find_unicode_block(original_chr).is_some_and(|block| {
+                    let block_name = block.name().to_lowercase();
+                    params
+                        .split_text_lower
+                        .iter()
+                        .any(|text| block_name.contains(text))
+                }))
+            || params.split_text_lower.iter().any(|text| {
+                glyph_names::glyph_name(chr as _).is_some_and(|t| t.contains(text))
+            })
+            || (params.fuzzy
+                && params.text.chars().any(|c| {
+                    unicode_skeleton::confusable([chr].into_iter(), [c].into_iter())
+                }))
+            // Checked last as this is the loosest way a name can match.
+            || (params.search_name
+                && params.fuzzy
+                && params
+                    .split_text_lower
+                    .iter()
+                    .any(|text| Self::abbreviation_match(&name, text)))
+    }
+
+    /// Returns `true` if the characters of `abbreviation` are the initials of
+    /// consecutive words in `name`, e.g. `"lsla"` for `"latin small letter a"`.
+    ///
+    /// Abbreviations shorter than three characters are ignored as they would
+    /// match a huge number of names.
+    fn abbreviation_match(name: &str, abbreviation: &str) -> bool {
+        if abbreviation.chars().count() < 3
+            || !abbreviation.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return false;
+        }
+
+        let initials = name
+            .split([' ', '-'])
+            .filter_map(|word| word.chars().next())
+            .collect::<String>();
+
+        initials.contains(abbreviation)
+    }
+}
+
+/// Returns `true` if `chr` has the Unicode `Deprecated` property or is in
+/// the Tags block. Neither belongs in running text.
+pub fn is_deprecated_or_tag(chr: char) -> bool {
+    matches!(
+        chr,
+        '\u{0149}'
+            | '\u{0673}'
+            | '\u{0F77}'
+            | '\u{0F79}'
+            | '\u{17A3}'
+            | '\u{17A4}'
+            | '\u{206A}'..='\u{206F}'
+            | '\u{2329}'
+            | '\u{232A}'
+            | '\u{E0000}'..='\u{E007F}'
+    )
+}
+
+/// Strips common English inflections off `word`, e.g. `arrows` becomes
+/// `arrow`, `pointing` becomes `point` and `butterflies` `butterfly`.
+///
+/// This is deliberately crude and can over-match, e.g. `string` becomes
+/// `str`, which is why it is optional.
+pub fn stem(word: &str) -> String {
+    const SUFFIXES: [(&str, &str); 8] = [
+        ("ies", "y"),
+        ("sses", "ss"),
+        ("xes", "x"),
+        ("ches", "ch"),
+        ("shes", "sh"),
+        ("ing", ""),
+        ("ed", ""),
+        ("s", ""),
+    ];
+
+    SUFFIXES
+        .iter()
+        .find_map(|(suffix, replacement)| {
+            let stem = word.strip_suffix(suffix)?.to_string() + replacement;
+            let keep = stem.chars().count() < 3
+                // E.g. `speed`.
+                || ("ed" == *suffix && stem.ends_with('e'))
+                // E.g. `cross`, `bus`, `iris`.
+                || ("s" == *suffix && stem.ends_with(['s', 'u', 'i']));
+
+            (!keep).then_some(stem)
+        })
+        .unwrap_or_else(|| word.to_string())
+}
+
+/// [`stem()`]s each word of `name`.
+fn stem_words(name: &str) -> String {
+    name.split([' ', '-'])
+        .map(stem)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Removes combining marks after decomposing `text`, e.g. `é` becomes `e`.
+fn strip_diacritics(text: &str) -> String {
+    text.nfd().filter(|c| !c.is_mark_nonspacing()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Searches names, ignoring case and diacritics; nothing else is on.
+    fn params(text: &str) -> SearchParams {
+        SearchParams::new(text, false, true, true, false, false, false)
+    }
+
+    /// The characters of `chars` by their lower case names, as the app
+    /// caches them.
+    fn cache(chars: impl IntoIterator<Item = char>) -> BTreeMap<char, String> {
+        chars
+            .into_iter()
+            .filter_map(|chr| Some((chr, unicode_names2::name(chr)?.to_string().to_lowercase())))
+            .collect()
+    }
+
+    /// What [`SearchEngine::search()`] finds, best first.
+    fn ranked(full_cache: &BTreeMap<char, String>, params: &SearchParams) -> Vec<char> {
+        let mut ranking = SearchEngine::search(full_cache, params)
+            .iter()
+            .map(|(&chr, name)| (chr, SearchEngine::relevance(chr, name, params)))
+            .collect::<Vec<_>>();
+        ranking.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        ranking.into_iter().map(|(chr, _)| chr).collect()
+    }
+
+    #[test]
+    fn full_name_resolves_to_one_character() {
+        let params = params("GREEK SMALL LETTER ALPHA");
+        let full_cache = cache('\u{0370}'..='\u{03FF}');
+
+        let top_tier = SearchEngine::search(&full_cache, &params)
+            .iter()
+            .filter(|(&chr, name)| 7.0 <= SearchEngine::relevance(chr, name, &params))
+            .map(|(&chr, _)| chr)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!['α'], top_tier);
+        assert_eq!(Some(&'α'), ranked(&full_cache, &params).first());
+    }
+
+    #[test]
+    fn full_name_keeps_other_results() {
+        let results = ranked(&cache('A'..='ʯ'), &params("latin small letter a"));
+
+        assert_eq!(Some(&'a'), results.first());
+        assert!(results.contains(&'à'));
+    }
+
+    #[test]
+    fn stemmed_terms_match_other_word_forms() {
+        let full_cache = cache(['→', '⇒', 'A']);
+        let mut params = params("arrows");
+        assert!(SearchEngine::search(&full_cache, &params).is_empty());
+
+        params = SearchParams::new("arrows", false, true, true, false, true, false);
+        let results = SearchEngine::search(&full_cache, &params);
+        assert!(results.contains_key(&'→'));
+        assert!(!results.contains_key(&'A'));
+    }
+
+    #[test]
+    fn stemming_keeps_full_names() {
+        let params = SearchParams::new(
+            "white up pointing index",
+            false,
+            true,
+            true,
+            false,
+            true,
+            false,
+        );
+
+        assert_eq!(Some('☝'), params.full_name_match);
+    }
+
+    #[test]
+    fn initials_find_names_but_rank_below_them() {
+        let params = SearchParams::new("lsla", false, true, true, false, false, true);
+        let full_cache = BTreeMap::from([
+            ('a', "latin small letter a".to_string()),
+            ('b', "latin small letter b".to_string()),
+            ('x', "lsla".to_string()),
+        ]);
+
+        assert_eq!(vec!['x', 'a'], ranked(&full_cache, &params));
+    }
+
+    #[test]
+    fn combining_class_pattern() {
+        let results = SearchEngine::search(&cache('\0'..='\u{036F}'), &params("ccc:230"));
+
+        assert!(results.contains_key(&'\u{0301}'));
+        assert!(!results.contains_key(&'a'));
+        assert!(!results.contains_key(&'\u{0316}'));
+    }
+
+    #[test]
+    fn line_break_class_pattern() {
+        let full_cache = cache(('a'..='z').chain('\u{2000}'..='\u{206F}'));
+
+        assert_eq!(
+            vec!['\u{2028}', '\u{2029}'],
+            SearchEngine::search(&full_cache, &params("lb:BK"))
+                .into_keys()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn shortcodes() {
+        let full_cache = cache(['😀', '😺', 'g']);
+
+        assert_eq!(
+            vec!['😀'],
+            SearchEngine::search(&full_cache, &params(":grinning:"))
+                .into_keys()
+                .collect::<Vec<_>>()
+        );
+        // Only unknown shortcodes are searched as names.
+        assert!(!params(":grinning: cat")
+            .split_text_lower
+            .contains(&"grinning".to_string()));
+        assert_eq!(
+            vec!["face", "grinning"],
+            params(":face_grinning:").split_text_lower
+        );
+    }
+
+    #[test]
+    fn block_names_match_when_fuzzy() {
+        let full_cache = cache(['→', '⇒', 'B']);
+        let fuzzy = SearchParams::new("arrows", false, true, true, false, false, true);
+
+        let results = SearchEngine::search(&full_cache, &fuzzy);
+        assert!(results.contains_key(&'→'));
+        assert!(results.contains_key(&'⇒'));
+        assert!(!results.contains_key(&'B'));
+    }
+
+    #[test]
+    fn diacritics_are_ignored() {
+        let mut full_cache = cache(['₣', '€']);
+        full_cache.insert('é', "café".to_string());
+
+        for query in ["franc", "fránc"] {
+            let results = SearchEngine::search(&full_cache, &params(query));
+            assert!(results.contains_key(&'₣'), "{query}");
+            assert!(!results.contains_key(&'€'), "{query}");
+        }
+        assert!(SearchEngine::search(&full_cache, &params("cafe")).contains_key(&'é'));
+    }
+
+    #[test]
+    fn deprecated_characters_only_on_request() {
+        let mut full_cache = cache(['ŉ', 'n']);
+        full_cache.insert('\u{E0041}', "tag latin capital letter a".to_string());
+        let mut params = params("letter n");
+        assert_eq!(
+            vec!['n'],
+            SearchEngine::search(&full_cache, &params)
+                .into_keys()
+                .collect::<Vec<_>>()
+        );
+
+        params.include_deprecated = true;
+        assert!(SearchEngine::search(&full_cache, &params).contains_key(&'ŉ'));
+
+        params = self::params("tag latin capital letter a");
+        assert!(!SearchEngine::search(&full_cache, &params).contains_key(&'\u{E0041}'));
+    }
+
+    #[test]
+    fn deprecated_characters_only_on_request_by_codepoint_or_pattern() {
+        let full_cache = cache(['\u{0300}', '\u{206A}', '\u{E0041}']);
+
+        for (query, deprecated) in [("U+E0041", '\u{E0041}'), ("lb:CM", '\u{206A}')] {
+            let mut params = params(query);
+            assert!(!SearchEngine::search(&full_cache, &params).contains_key(&deprecated));
+
+            params.include_deprecated = true;
+            assert!(SearchEngine::search(&full_cache, &params).contains_key(&deprecated));
+        }
+        assert!(SearchEngine::search(&full_cache, &params("lb:CM")).contains_key(&'\u{0300}'));
+    }
+
+    #[test]
+    fn excluded_characters() {
+        let full_cache = cache('a'..='e');
+        let mut params = params("latin small letter");
+        params.excluded_chars = HashSet::from_iter(['b', 'c']);
+
+        assert_eq!(
+            vec!['a', 'd', 'e'],
+            SearchEngine::search(&full_cache, &params)
+                .into_keys()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn excluded_characters_by_codepoint_or_pattern() {
+        let full_cache = cache(['\u{0300}', '\u{0301}']);
+        let mut params = params("ccc:230 U+0301");
+        params.excluded_chars = HashSet::from_iter(['\u{0301}']);
+
+        assert_eq!(
+            vec!['\u{0300}'],
+            SearchEngine::search(&full_cache, &params)
+                .into_keys()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn numeric_value_pattern() {
+        let full_cache = cache(['½', '¼', '5', 'Ⅶ']);
+
+        for query in ["num:1/2", "num:0.5"] {
+            assert_eq!(
+                vec!['½'],
+                SearchEngine::search(&full_cache, &params(query))
+                    .into_keys()
+                    .collect::<Vec<_>>()
+            );
+        }
+        assert!(params("num:seven").escaped_chars.is_empty());
+        assert!(params("num:1/0").escaped_chars.is_empty());
+    }
+
+    #[test]
+    fn codepoints_need_a_prefix_below_four_digits() {
+        let full_cache = cache(['A', '😀', '4', '1']);
+        let results = |query| {
+            SearchEngine::search(&full_cache, &params(query))
+                .into_keys()
+                .collect::<Vec<_>>()
+        };
+
+        assert!(!results("41").contains(&'A'));
+        for query in ["U+41", "u+41", "0x41", "U+0041"] {
+            assert_eq!(vec!['A'], results(query), "{query}");
+        }
+        assert!(results("1f600").contains(&'😀'));
+        assert!(results("1F600").contains(&'😀'));
+    }
+
+    #[test]
+    fn name_and_fuzzy_toggles() {
+        // Latin and Cyrillic e, which are confusable.
+        let full_cache = cache(['→', 'e', 'е']);
+        let results = |query, search_name, fuzzy| {
+            SearchEngine::search(
+                &full_cache,
+                &SearchParams::new(query, false, search_name, true, false, false, fuzzy),
+            )
+            .into_keys()
+            .collect::<Vec<_>>()
+        };
+
+        // By name, strictly and by block name.
+        assert_eq!(vec!['→'], results("arrows", true, true));
+        assert!(results("arrows", true, false).is_empty());
+        assert_eq!(vec!['→'], results("arrow", true, false));
+        // By character, strictly and by what it is confusable with.
+        assert_eq!(vec!['e', 'е'], results("e", false, true));
+        assert_eq!(vec!['e'], results("e", false, false));
+    }
+
+    #[test]
+    fn excluded_terms() {
+        let full_cache = cache(['a', 'A', 'α', '-', '‐']);
+        let results = |query| {
+            SearchEngine::search(&full_cache, &params(query))
+                .into_keys()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!['a'], results("latin -capital"));
+        // Excluded terms alone narrow everything down.
+        assert_eq!(vec!['-', 'a', 'α', '‐'], results("-capital"));
+        // A lone `-` is a hyphen to search for.
+        assert!(params("-").excluded_terms.is_empty());
+        assert_eq!(vec!['-'], results("- minus"));
+    }
+
+    #[test]
+    fn excluded_terms_narrow_patterns() {
+        let full_cache = cache(('\u{0300}'..='\u{0303}').chain(['a']));
+        let results = |query| {
+            SearchEngine::search(&full_cache, &params(query))
+                .into_keys()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec!['\u{0300}', '\u{0302}', '\u{0303}'],
+            results("ccc:230 -acute")
+        );
+        assert_eq!(vec!['\u{0300}'], results("U+0301 grave -acute"));
+    }
+}
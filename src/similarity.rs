@@ -0,0 +1,227 @@
+use crate::raster::GlyphRasterizer;
+use ahash::AHashMap as HashMap;
+use image::{imageops, GrayImage};
+use unicode_skeleton::UnicodeSkeleton;
+
+/// Finds glyphs that look alike by comparing perceptual hashes of their
+/// renderings.
+///
+/// A glyph is rasterized small, centered on a square canvas, and shrunk to
+/// 8×8 pixels. Each bit of its hash says whether a pixel is darker than the
+/// average. The number of bits two hashes differ in is their distance.
+///
+/// Drawings are hashed the same way, compared with just the ink of glyphs.
+#[derive(Default)]
+pub struct SimilarityCache {
+    // `None` for glyphs without ink or a font.
+    hashes: HashMap<char, Option<u64>>,
+    // Like `hashes` but of the glyph's ink alone, without where it sits in
+    // the line; what drawings are compared with.
+    shape_hashes: HashMap<char, Option<u64>>,
+}
+
+impl SimilarityCache {
+    /// The size glyphs are rasterized at for hashing.
+    const RASTER_SIZE: f32 = 16.0;
+    /// Candidates beyond this many are ignored to keep it responsive.
+    pub const MAX_CANDIDATES: usize = 4096;
+
+    /// Returns the `count` glyphs of `candidates` that look most like `chr`,
+    /// closest first.
+    pub fn find_similar(
+        &mut self,
+        rasterizer: &GlyphRasterizer,
+        chr: char,
+        candidates: impl IntoIterator<Item = char>,
+        count: usize,
+    ) -> Vec<char> {
+        let hashes = &mut self.hashes;
+        let mut hash = |chr| {
+            *hashes
+                .entry(chr)
+                .or_insert_with(|| glyph_hash(rasterizer, chr, false))
+        };
+
+        let Some(chr_hash) = hash(chr) else {
+            return Vec::new();
+        };
+
+        closest(
+            chr_hash,
+            candidates.into_iter().filter(|&candidate| candidate != chr),
+            count,
+            hash,
+        )
+    }
+
+    /// Returns the `count` glyphs of `candidates` that look most like the
+    /// `strokes` drawn by the user, closest first.
+    ///
+    /// Each stroke is a line through points on a canvas from `0.0` to `1.0`
+    /// on both axes, `y` pointing down.
+    pub fn find_like_drawing(
+        &mut self,
+        rasterizer: &GlyphRasterizer,
+        strokes: &[Vec<[f32; 2]>],
+        candidates: impl IntoIterator<Item = char>,
+        count: usize,
+    ) -> Vec<char> {
+        let Some(drawing_hash) = drawing_hash(strokes) else {
+            return Vec::new();
+        };
+
+        let shape_hashes = &mut self.shape_hashes;
+        closest(drawing_hash, candidates, count, |chr| {
+            *shape_hashes
+                .entry(chr)
+                .or_insert_with(|| glyph_hash(rasterizer, chr, true))
+        })
+    }
+}
+
+/// The characters of `candidates` the Unicode confusables data (UTS #39)
+/// considers indistinguishable from `chr`, e.g. Cyrillic `а` for Latin `a`.
+/// Unlike [`SimilarityCache::find_similar()`] this doesn't depend on the
+/// fonts, which is what matters for spotting spoofed text.
+pub fn confusables(chr: char, candidates: impl IntoIterator<Item = char>) -> Vec<char> {
+    let skeleton = [chr].into_iter().skeleton_chars().collect::<Vec<_>>();
+
+    candidates
+        .into_iter()
+        .filter(|&candidate| {
+            candidate != chr
+                && [candidate]
+                    .into_iter()
+                    .skeleton_chars()
+                    .eq(skeleton.iter().copied())
+        })
+        .collect()
+}
+
+/// The `count` of `candidates` whose hash, from `hash`, is closest to
+/// `target`.
+fn closest(
+    target: u64,
+    candidates: impl IntoIterator<Item = char>,
+    count: usize,
+    mut hash: impl FnMut(char) -> Option<u64>,
+) -> Vec<char> {
+    let mut similar = candidates
+        .into_iter()
+        .take(SimilarityCache::MAX_CANDIDATES)
+        .filter_map(|candidate| {
+            let distance = (target ^ hash(candidate)?).count_ones();
+            Some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+
+    similar.sort_unstable();
+    similar.truncate(count);
+
+    similar.into_iter().map(|(_, chr)| chr).collect()
+}
+
+/// Hashes the rendering of `chr`, only its ink if `shape_only`.
+fn glyph_hash(rasterizer: &GlyphRasterizer, chr: char, shape_only: bool) -> Option<u64> {
+    let glyph = rasterizer.rasterize(chr, SimilarityCache::RASTER_SIZE, true)?;
+    let [width, height] = glyph.size;
+
+    let image = GrayImage::from_fn(width as _, height as _, |x, y| {
+        image::Luma([glyph.pixels[y as usize * width + x as usize].a()])
+    });
+
+    if shape_only {
+        ink_hash(&crop_to_ink(&image)?)
+    } else {
+        ink_hash(&image)
+    }
+}
+
+/// The size drawings are rasterized at for hashing. Strokes are as thick
+/// as those of a glyph at `SimilarityCache::RASTER_SIZE`, relatively.
+const DRAWING_SIZE: u32 = 64;
+const DRAWING_STROKE_RADIUS: f32 = 3.0;
+
+fn drawing_hash(strokes: &[Vec<[f32; 2]>]) -> Option<u64> {
+    let mut drawing = GrayImage::new(DRAWING_SIZE, DRAWING_SIZE);
+    let scale = (DRAWING_SIZE - 1) as f32;
+
+    // Stamp a disc every half pixel along each line.
+    let mut stamp = |[x, y]: [f32; 2]| {
+        let (x, y) = (x * scale, y * scale);
+        let radius = DRAWING_STROKE_RADIUS;
+        for py in (y - radius).max(0.0) as u32..=((y + radius).min(scale) as u32) {
+            for px in (x - radius).max(0.0) as u32..=((x + radius).min(scale) as u32) {
+                if (px as f32 - x).hypot(py as f32 - y) <= radius {
+                    drawing.put_pixel(px, py, image::Luma([u8::MAX]));
+                }
+            }
+        }
+    };
+
+    for stroke in strokes {
+        if let [point] = stroke.as_slice() {
+            stamp(*point);
+        }
+        for line in stroke.windows(2) {
+            let ([x0, y0], [x1, y1]) = (line[0], line[1]);
+            let steps = (2.0 * scale * (x1 - x0).hypot(y1 - y0)).ceil().max(1.0) as usize;
+            for step in 0..=steps {
+                let t = step as f32 / steps as f32;
+                stamp([x0 + t * (x1 - x0), y0 + t * (y1 - y0)]);
+            }
+        }
+    }
+
+    ink_hash(&crop_to_ink(&drawing)?)
+}
+
+/// The bounding box of what is not blank in `image`; `None` if nothing is.
+fn crop_to_ink(image: &GrayImage) -> Option<GrayImage> {
+    let (left, top, right, bottom) = image
+        .enumerate_pixels()
+        .filter(|(_, _, pixel)| 0 != pixel.0[0])
+        .fold(
+            (u32::MAX, u32::MAX, 0, 0),
+            |(left, top, right, bottom), (x, y, _)| {
+                (left.min(x), top.min(y), right.max(x), bottom.max(y))
+            },
+        );
+
+    (left <= right).then(|| {
+        imageops::crop_imm(image, left, top, right - left + 1, bottom - top + 1).to_image()
+    })
+}
+
+/// Hashes the ink of `image`, a glyph's coverage.
+fn ink_hash(image: &GrayImage) -> Option<u64> {
+    let (width, height) = image.dimensions();
+
+    // Center the glyph on a square so its aspect ratio survives shrinking.
+    let side = width.max(height);
+    let mut canvas = GrayImage::new(side, side);
+    imageops::overlay(
+        &mut canvas,
+        image,
+        ((side - width) / 2) as _,
+        ((side - height) / 2) as _,
+    );
+
+    let pixels = imageops::resize(&canvas, 8, 8, imageops::FilterType::Triangle).into_raw();
+    let sum = pixels.iter().map(|&p| p as u32).sum::<u32>();
+
+    // Nothing to compare for glyphs without ink.
+    if 0 == sum {
+        return None;
+    }
+
+    let mean = sum / pixels.len() as u32;
+
+    Some(
+        pixels
+            .iter()
+            .enumerate()
+            .filter(|(_, &p)| mean < p as u32)
+            .fold(0, |hash, (i, _)| hash | 1 << i),
+    )
+}
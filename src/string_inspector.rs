@@ -0,0 +1,46 @@
+use unicode_normalization::char::canonical_combining_class;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A user-perceived character of an inspected string.
+pub struct Grapheme<'a> {
+    pub text: &'a str,
+    /// The CLDR short name of an emoji sequence, e.g.
+    /// `family: woman, woman, girl`.
+    pub sequence_name: Option<&'static str>,
+}
+
+/// Splits `text` into extended grapheme clusters and names the ones that are
+/// emoji sequences.
+pub fn graphemes(text: &str) -> Vec<Grapheme<'_>> {
+    text.graphemes(true)
+        .map(|text| Grapheme {
+            text,
+            sequence_name: sequence_name(text),
+        })
+        .collect()
+}
+
+/// The name of `grapheme` if it is an emoji made up of several codepoints,
+/// e.g. a ZWJ, keycap, flag or skin tone sequence.
+pub fn sequence_name(grapheme: &str) -> Option<&'static str> {
+    // Single codepoints are named by the inspector already.
+    grapheme.chars().nth(1)?;
+
+    emojis::get(grapheme)
+        // Pasted text often lacks or has extra emoji presentation selectors.
+        .or_else(|| emojis::get(&grapheme.replace('\u{FE0F}', "")))
+        .map(|emoji| emoji.name())
+}
+
+/// `text` with each run of combining marks sorted by their canonical
+/// combining class, as normalization does before composing. Marks of the
+/// same class keep their order, so do characters of class 0.
+pub fn canonical_order(text: &str) -> Vec<char> {
+    let mut chars = text.chars().collect::<Vec<_>>();
+
+    for run in chars.split_mut(|&chr| 0 == canonical_combining_class(chr)) {
+        run.sort_by_key(|&chr| canonical_combining_class(chr));
+    }
+
+    chars
+}
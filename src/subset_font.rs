@@ -0,0 +1,714 @@
+// Writes a font restricted to a few characters, e.g. to serve just the
+// glyphs of a collection on a web page.
+//
+// Glyphs are renumbered and only the tables needed to render them are kept.
+// The OpenType layout tables (`GSUB`, `GPOS`, `GDEF`) refer to glyph ids and
+// are dropped, as are vertical metrics. Outlines can be TrueType (`glyf`) or
+// CFF; CID-keyed CFF and CFF2 fonts are not supported.
+use ahash::AHashMap as HashMap;
+use std::{
+    collections::BTreeSet,
+    io::{Error, ErrorKind, Result},
+};
+use ttf_parser::{GlyphId, Tag};
+
+/// Tables copied as they are. They don't refer to glyph ids.
+const COPIED_TABLES: [&[u8; 4]; 5] = [b"cvt ", b"fpgm", b"gasp", b"name", b"prep"];
+
+/// The characters of `chars` face `index` of `data` has a glyph for, and a
+/// font containing only those.
+pub fn subset(data: &[u8], index: u32, chars: &[char]) -> Result<(Vec<char>, Vec<u8>)> {
+    let face = ttf_parser::Face::parse(data, index).map_err(invalid_data)?;
+    let table = |tag: &[u8; 4]| face.raw_face().table(Tag::from_bytes(tag));
+    let required_table = |tag: &[u8; 4]| {
+        table(tag).ok_or_else(|| {
+            invalid_data(format!(
+                "no `{}` table",
+                String::from_utf8_lossy(tag).trim_end()
+            ))
+        })
+    };
+
+    let mut mapping = chars
+        .iter()
+        .filter_map(|&chr| Some((chr, face.glyph_index(chr)?.0)))
+        .collect::<Vec<_>>();
+    mapping.sort_unstable();
+    mapping.dedup();
+
+    let glyf = table(b"glyf").map(|glyf| -> Result<_> {
+        let loca = Loca::new(required_table(b"loca")?, required_table(b"head")?)?;
+        Ok((glyf, loca))
+    });
+
+    // Glyph 0 is `.notdef` and must stay.
+    let mut glyphs = BTreeSet::from([0]);
+    glyphs.extend(mapping.iter().map(|(_, glyph)| *glyph));
+    if let Some(Ok((glyf, loca))) = &glyf {
+        // Composite glyphs need their components.
+        let mut pending = glyphs.iter().copied().collect::<Vec<_>>();
+        while let Some(glyph) = pending.pop() {
+            for (component, _) in components(loca.glyph(glyf, glyph)?)? {
+                if glyphs.insert(component) {
+                    pending.push(component);
+                }
+            }
+        }
+    }
+
+    let old_glyphs = glyphs.into_iter().collect::<Vec<_>>();
+    let new_glyphs = old_glyphs
+        .iter()
+        .enumerate()
+        .map(|(new, old)| (*old, new as u16))
+        .collect::<HashMap<_, _>>();
+    let glyph_count = old_glyphs.len() as u16;
+
+    let mut tables = Vec::<([u8; 4], Vec<u8>)>::new();
+
+    let sfnt_version = match (glyf, table(b"CFF ")) {
+        (Some(glyf), _) => {
+            let (glyf, loca) = glyf?;
+
+            let mut new_glyf = Vec::new();
+            let mut new_loca = Vec::new();
+            for old in &old_glyphs {
+                new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+                let old_glyph = loca.glyph(glyf, *old)?;
+                let mut glyph = old_glyph.to_vec();
+                for (component, offset) in components(old_glyph)? {
+                    glyph[offset..offset + 2]
+                        .copy_from_slice(&new_glyphs[&component].to_be_bytes());
+                }
+                new_glyf.extend(glyph);
+                pad(&mut new_glyf);
+            }
+            new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+            tables.push((*b"glyf", new_glyf));
+            tables.push((*b"loca", new_loca));
+
+            0x0001_0000_u32.to_be_bytes()
+        }
+        (None, Some(cff)) => {
+            tables.push((*b"CFF ", subset_cff(cff, &old_glyphs)?));
+
+            *b"OTTO"
+        }
+        (None, None) => {
+            return Err(invalid_data("no TrueType or CFF outlines"));
+        }
+    };
+
+    let mut head = required_table(b"head")?.to_vec();
+    check_length(&head, 54, "head")?;
+    // `checkSumAdjustment`, set once the font is complete.
+    head[8..12].fill(0);
+    // Long `loca` offsets.
+    head[50..52].copy_from_slice(&1_i16.to_be_bytes());
+    tables.push((*b"head", head));
+
+    let mut hhea = required_table(b"hhea")?.to_vec();
+    check_length(&hhea, 36, "hhea")?;
+    hhea[34..36].copy_from_slice(&glyph_count.to_be_bytes());
+    tables.push((*b"hhea", hhea));
+
+    let mut hmtx = Vec::new();
+    for old in &old_glyphs {
+        let glyph = GlyphId(*old);
+        hmtx.extend(face.glyph_hor_advance(glyph).unwrap_or(0).to_be_bytes());
+        hmtx.extend(
+            face.glyph_hor_side_bearing(glyph)
+                .unwrap_or(0)
+                .to_be_bytes(),
+        );
+    }
+    tables.push((*b"hmtx", hmtx));
+
+    let mut maxp = required_table(b"maxp")?.to_vec();
+    check_length(&maxp, 6, "maxp")?;
+    maxp[4..6].copy_from_slice(&glyph_count.to_be_bytes());
+    tables.push((*b"maxp", maxp));
+
+    // Version 3 has no glyph names, which would have to be renumbered.
+    let mut post = required_table(b"post")?.to_vec();
+    check_length(&post, 32, "post")?;
+    post.truncate(32);
+    post[0..4].copy_from_slice(&0x0003_0000_u32.to_be_bytes());
+    tables.push((*b"post", post));
+
+    let cmap_entries = mapping
+        .iter()
+        .map(|(chr, glyph)| (*chr as u32, new_glyphs[glyph]))
+        .collect::<Vec<_>>();
+    tables.push((*b"cmap", cmap(&cmap_entries)));
+
+    if let Some(os2) = table(b"OS/2") {
+        let mut os2 = os2.to_vec();
+        if 68 <= os2.len() {
+            let bmp = |codepoint: u32| codepoint.min(0xFFFF) as u16;
+            let first = cmap_entries
+                .first()
+                .map_or(0, |(codepoint, _)| bmp(*codepoint));
+            let last = cmap_entries
+                .last()
+                .map_or(0, |(codepoint, _)| bmp(*codepoint));
+            os2[64..66].copy_from_slice(&first.to_be_bytes());
+            os2[66..68].copy_from_slice(&last.to_be_bytes());
+        }
+        tables.push((*b"OS/2", os2));
+    }
+
+    for tag in COPIED_TABLES {
+        if let Some(data) = table(tag) {
+            tables.push((*tag, data.to_vec()));
+        }
+    }
+
+    Ok((
+        mapping.into_iter().map(|(chr, _)| chr).collect(),
+        sfnt(sfnt_version, tables),
+    ))
+}
+
+/// Where each glyph's data starts in the `glyf` table.
+struct Loca(Vec<usize>);
+
+impl Loca {
+    fn new(loca: &[u8], head: &[u8]) -> Result<Self> {
+        check_length(head, 54, "head")?;
+        let offsets = if 0 == i16::from_be_bytes([head[50], head[51]]) {
+            loca.chunks_exact(2)
+                .map(|offset| 2 * u16::from_be_bytes([offset[0], offset[1]]) as usize)
+                .collect()
+        } else {
+            loca.chunks_exact(4)
+                .map(|offset| u32::from_be_bytes(offset.try_into().unwrap()) as usize)
+                .collect()
+        };
+
+        Ok(Self(offsets))
+    }
+
+    fn glyph<'a>(&self, glyf: &'a [u8], glyph: u16) -> Result<&'a [u8]> {
+        let glyph = glyph as usize;
+        self.0
+            .get(glyph..glyph + 2)
+            .and_then(|range| glyf.get(range[0]..range[1]))
+            .ok_or_else(|| invalid_data(format!("glyph {glyph} is outside the `glyf` table")))
+    }
+}
+
+/// The components of a composite TrueType glyph, each with the offset of its
+/// glyph id in `glyph`. Simple glyphs have none.
+fn components(glyph: &[u8]) -> Result<Vec<(u16, usize)>> {
+    const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+    const WE_HAVE_A_SCALE: u16 = 0x0008;
+    const MORE_COMPONENTS: u16 = 0x0020;
+    const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+    const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+    let mut components = Vec::new();
+    if glyph.len() < 10 || 0 <= i16::from_be_bytes([glyph[0], glyph[1]]) {
+        return Ok(components);
+    }
+
+    let mut offset = 10;
+    loop {
+        let Some(component) = glyph.get(offset..offset + 4) else {
+            return Err(invalid_data("truncated composite glyph"));
+        };
+        let flags = u16::from_be_bytes([component[0], component[1]]);
+        components.push((u16::from_be_bytes([component[2], component[3]]), offset + 2));
+
+        offset += 4;
+        offset += if 0 != flags & ARG_1_AND_2_ARE_WORDS {
+            4
+        } else {
+            2
+        };
+        offset += if 0 != flags & WE_HAVE_A_SCALE {
+            2
+        } else if 0 != flags & WE_HAVE_AN_X_AND_Y_SCALE {
+            4
+        } else if 0 != flags & WE_HAVE_A_TWO_BY_TWO {
+            8
+        } else {
+            0
+        };
+
+        if 0 == flags & MORE_COMPONENTS {
+            return Ok(components);
+        }
+    }
+}
+
+/// A `CFF ` table with only the charstrings of `glyphs`, in that order.
+fn subset_cff(cff: &[u8], glyphs: &[u16]) -> Result<Vec<u8>> {
+    const CHARSET: u16 = 15;
+    const ENCODING: u16 = 16;
+    const CHAR_STRINGS: u16 = 17;
+    const PRIVATE: u16 = 18;
+    const SUBRS: u16 = 19;
+    const ROS: u16 = 0x0C00 | 30;
+
+    let header_size = *cff.get(2).ok_or_else(|| invalid_data("truncated CFF"))? as usize;
+    let (_, name_index_end) = cff_index(cff, header_size)?;
+    let (top_dicts, top_dict_index_end) = cff_index(cff, name_index_end)?;
+    let (_, string_index_end) = cff_index(cff, top_dict_index_end)?;
+    let (_, global_subrs_end) = cff_index(cff, string_index_end)?;
+
+    let top_dict = cff_dict(
+        top_dicts
+            .first()
+            .ok_or_else(|| invalid_data("no CFF font"))?,
+    )?;
+    let operands = |operator| {
+        top_dict
+            .iter()
+            .find(|entry| operator == entry.operator)
+            .map(|entry| entry.operands.as_slice())
+    };
+    if operands(ROS).is_some() {
+        return Err(invalid_data("CID-keyed CFF fonts are not supported"));
+    }
+
+    let offset = |operands: Option<&[i32]>, index| {
+        operands
+            .and_then(|operands| operands.get(index))
+            .and_then(|offset| usize::try_from(*offset).ok())
+    };
+
+    let char_strings_offset =
+        offset(operands(CHAR_STRINGS), 0).ok_or_else(|| invalid_data("no CFF charstrings"))?;
+    let (char_strings, _) = cff_index(cff, char_strings_offset)?;
+
+    let sids = cff_charset(
+        cff,
+        offset(operands(CHARSET), 0).unwrap_or(0),
+        char_strings.len(),
+    )?;
+
+    // The private DICT and the local subroutines, which follow it at an
+    // offset relative to its start, are copied as they are.
+    let private = operands(PRIVATE)
+        .map(|private| -> Result<_> {
+            let size = offset(Some(private), 0).unwrap_or(0);
+            let start = offset(Some(private), 1).unwrap_or(0);
+            let dict = cff
+                .get(start..start + size)
+                .ok_or_else(|| invalid_data("private DICT is outside the CFF table"))?;
+
+            let end = match cff_dict(dict)?
+                .iter()
+                .find(|entry| SUBRS == entry.operator)
+                .and_then(|entry| usize::try_from(*entry.operands.first()?).ok())
+            {
+                Some(subrs) => cff_index(cff, start + subrs)?.1,
+                None => start + size,
+            };
+
+            Ok((size, &cff[start..end.max(start + size)]))
+        })
+        .transpose()?;
+
+    let mut charset = vec![0];
+    for glyph in glyphs.iter().skip(1) {
+        let sid = sids
+            .get(*glyph as usize)
+            .ok_or_else(|| invalid_data(format!("glyph {glyph} is not in the CFF charset")))?;
+        charset.extend(sid.to_be_bytes());
+    }
+
+    let char_strings = write_cff_index(
+        &glyphs
+            .iter()
+            .map(|glyph| {
+                char_strings
+                    .get(*glyph as usize)
+                    .copied()
+                    .ok_or_else(|| invalid_data(format!("glyph {glyph} has no CFF charstring")))
+            })
+            .collect::<Result<Vec<_>>>()?,
+    );
+
+    // Offsets are written as five byte integers so the size of the top DICT
+    // doesn't depend on them.
+    let write_top_dict = |charset_offset: i32, char_strings_offset: i32, private_offset: i32| {
+        let mut dict = Vec::new();
+        for entry in &top_dict {
+            match entry.operator {
+                CHARSET => write_cff_integer(&mut dict, charset_offset),
+                // The `cmap` table maps characters to glyphs.
+                ENCODING => continue,
+                CHAR_STRINGS => write_cff_integer(&mut dict, char_strings_offset),
+                PRIVATE => match private {
+                    Some((size, _)) => {
+                        write_cff_integer(&mut dict, size as i32);
+                        write_cff_integer(&mut dict, private_offset);
+                    }
+                    None => continue,
+                },
+                _ => dict.extend(entry.raw_operands),
+            }
+            dict.extend(entry.raw_operator);
+        }
+        write_cff_index(&[&dict])
+    };
+
+    let charset_offset =
+        name_index_end + write_top_dict(0, 0, 0).len() + (global_subrs_end - top_dict_index_end);
+    let char_strings_offset = charset_offset + charset.len();
+    let private_offset = char_strings_offset + char_strings.len();
+
+    let mut new_cff = cff[..name_index_end].to_vec();
+    new_cff.extend(write_top_dict(
+        charset_offset as i32,
+        char_strings_offset as i32,
+        private_offset as i32,
+    ));
+    // The string and global subroutine INDEXes.
+    new_cff.extend(&cff[top_dict_index_end..global_subrs_end]);
+    new_cff.extend(charset);
+    new_cff.extend(char_strings);
+    if let Some((_, private)) = private {
+        new_cff.extend(private);
+    }
+
+    Ok(new_cff)
+}
+
+/// The items of the CFF INDEX at `offset` and where the INDEX ends.
+fn cff_index(cff: &[u8], offset: usize) -> Result<(Vec<&[u8]>, usize)> {
+    let truncated = || invalid_data("truncated CFF INDEX");
+
+    let count = cff.get(offset..offset + 2).ok_or_else(truncated)?;
+    let count = u16::from_be_bytes([count[0], count[1]]) as usize;
+    if 0 == count {
+        return Ok((Vec::new(), offset + 2));
+    }
+
+    let offset_size = *cff.get(offset + 2).ok_or_else(truncated)? as usize;
+    let offsets = cff
+        .get(offset + 3..offset + 3 + (count + 1) * offset_size)
+        .ok_or_else(truncated)?
+        .chunks_exact(offset_size)
+        .map(|bytes| {
+            bytes
+                .iter()
+                .fold(0, |offset, byte| offset << 8 | *byte as usize)
+        })
+        .collect::<Vec<_>>();
+
+    // Offsets are relative to the byte before the item data.
+    let base = offset + 2 + (count + 1) * offset_size;
+    let items = offsets
+        .windows(2)
+        .map(|range| {
+            cff.get(base + range[0]..base + range[1])
+                .ok_or_else(truncated)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((items, base + offsets[count]))
+}
+
+fn write_cff_index(items: &[&[u8]]) -> Vec<u8> {
+    let mut index = (items.len() as u16).to_be_bytes().to_vec();
+    if items.is_empty() {
+        return index;
+    }
+
+    index.push(4);
+    let mut offset = 1_u32;
+    index.extend(offset.to_be_bytes());
+    for item in items {
+        offset += item.len() as u32;
+        index.extend(offset.to_be_bytes());
+    }
+    for item in items {
+        index.extend(*item);
+    }
+
+    index
+}
+
+/// An operator of a CFF DICT with its operands. Two byte operators are
+/// `0x0C00 | second byte`.
+struct CffDictEntry<'a> {
+    operator: u16,
+    /// Only integer operands; reals are skipped.
+    operands: Vec<i32>,
+    raw_operands: &'a [u8],
+    raw_operator: &'a [u8],
+}
+
+fn cff_dict(dict: &[u8]) -> Result<Vec<CffDictEntry<'_>>> {
+    let truncated = || invalid_data("truncated CFF DICT");
+    let byte = |index: usize| {
+        dict.get(index)
+            .copied()
+            .map(i32::from)
+            .ok_or_else(truncated)
+    };
+
+    let mut entries = Vec::new();
+    let mut operands = Vec::new();
+    let mut operands_start = 0;
+    let mut index = 0;
+    while index < dict.len() {
+        let b0 = byte(index)?;
+        match b0 {
+            0..=21 => {
+                let (operator, size) = if 12 == b0 {
+                    (0x0C00 | byte(index + 1)? as u16, 2)
+                } else {
+                    (b0 as u16, 1)
+                };
+                entries.push(CffDictEntry {
+                    operator,
+                    operands: std::mem::take(&mut operands),
+                    raw_operands: &dict[operands_start..index],
+                    raw_operator: &dict[index..index + size],
+                });
+                index += size;
+                operands_start = index;
+            }
+            28 => {
+                operands.push(
+                    i16::from_be_bytes([byte(index + 1)? as u8, byte(index + 2)? as u8]) as i32,
+                );
+                index += 3;
+            }
+            29 => {
+                let bytes = dict.get(index + 1..index + 5).ok_or_else(truncated)?;
+                operands.push(i32::from_be_bytes(bytes.try_into().unwrap()));
+                index += 5;
+            }
+            30 => {
+                // A real, a nibble per character, up to the end nibble `F`.
+                index += 1;
+                while 0xF != byte(index)? & 0xF && 0xF0 != byte(index)? & 0xF0 {
+                    index += 1;
+                }
+                index += 1;
+            }
+            32..=246 => {
+                operands.push(b0 - 139);
+                index += 1;
+            }
+            247..=250 => {
+                operands.push((b0 - 247) * 256 + byte(index + 1)? + 108);
+                index += 2;
+            }
+            251..=254 => {
+                operands.push(-(b0 - 251) * 256 - byte(index + 1)? - 108);
+                index += 2;
+            }
+            _ => return Err(invalid_data(format!("reserved CFF DICT byte {b0}"))),
+        }
+    }
+
+    Ok(entries)
+}
+
+fn write_cff_integer(dict: &mut Vec<u8>, value: i32) {
+    dict.push(29);
+    dict.extend(value.to_be_bytes());
+}
+
+/// The string id of each glyph's name, from the charset at `offset`.
+fn cff_charset(cff: &[u8], offset: usize, glyph_count: usize) -> Result<Vec<u16>> {
+    let truncated = || invalid_data("truncated CFF charset");
+    let u16_at = |index: usize| {
+        cff.get(index..index + 2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+            .ok_or_else(truncated)
+    };
+
+    // The predefined ISOAdobe charset names glyph `n` with string `n`.
+    if 0 == offset {
+        return Ok((0..glyph_count as u16).collect());
+    }
+    if offset <= 2 {
+        return Err(invalid_data("expert CFF charsets are not supported"));
+    }
+
+    // `.notdef` is implied.
+    let mut sids = vec![0];
+    let format = *cff.get(offset).ok_or_else(truncated)?;
+    let mut index = offset + 1;
+    while sids.len() < glyph_count {
+        match format {
+            0 => {
+                sids.push(u16_at(index)?);
+                index += 2;
+            }
+            1 | 2 => {
+                let first = u16_at(index)?;
+                let left = if 1 == format {
+                    *cff.get(index + 2).ok_or_else(truncated)? as u16
+                } else {
+                    u16_at(index + 2)?
+                };
+                sids.extend((0..=left).map(|offset| first.wrapping_add(offset)));
+                index += if 1 == format { 3 } else { 4 };
+            }
+            _ => return Err(invalid_data(format!("unknown CFF charset format {format}"))),
+        }
+    }
+
+    Ok(sids)
+}
+
+/// A `cmap` table for `entries`, codepoints with their glyphs in codepoint
+/// order: a format 4 subtable for the Basic Multilingual Plane, which older
+/// systems need, and a format 12 one for all of them.
+fn cmap(entries: &[(u32, u16)]) -> Vec<u8> {
+    // Format 4 ends with a segment for 0xFFFF.
+    let bmp = entries
+        .iter()
+        .filter(|(codepoint, _)| *codepoint < 0xFFFF)
+        .chain([&(0xFFFF, 0)])
+        .collect::<Vec<_>>();
+    let segment_count = bmp.len() as u16;
+    let search_range = 2 * (1 << (15 - segment_count.leading_zeros())) as u16;
+
+    let mut format_4 = Vec::new();
+    for value in [
+        4,
+        16 + 8 * segment_count,
+        0,
+        2 * segment_count,
+        search_range,
+        search_range.trailing_zeros() as u16 - 1,
+        2 * segment_count - search_range,
+    ] {
+        format_4.extend(value.to_be_bytes());
+    }
+    // End codes, padding, start codes, deltas and range offsets, with a
+    // segment for each character.
+    for (codepoint, _) in &bmp {
+        format_4.extend((*codepoint as u16).to_be_bytes());
+    }
+    format_4.extend(0_u16.to_be_bytes());
+    for (codepoint, _) in &bmp {
+        format_4.extend((*codepoint as u16).to_be_bytes());
+    }
+    for (codepoint, glyph) in &bmp {
+        let delta = if 0xFFFF == *codepoint {
+            1
+        } else {
+            glyph.wrapping_sub(*codepoint as u16)
+        };
+        format_4.extend(delta.to_be_bytes());
+    }
+    for _ in &bmp {
+        format_4.extend(0_u16.to_be_bytes());
+    }
+
+    let mut format_12 = Vec::new();
+    format_12.extend(12_u16.to_be_bytes());
+    format_12.extend(0_u16.to_be_bytes());
+    format_12.extend((16 + 12 * entries.len() as u32).to_be_bytes());
+    format_12.extend(0_u32.to_be_bytes());
+    format_12.extend((entries.len() as u32).to_be_bytes());
+    for (codepoint, glyph) in entries {
+        format_12.extend(codepoint.to_be_bytes());
+        format_12.extend(codepoint.to_be_bytes());
+        format_12.extend((*glyph as u32).to_be_bytes());
+    }
+
+    // Format 4 subtables can't be longer than 64 KiB.
+    let subtables = if format_4.len() <= u16::MAX as usize {
+        vec![(1_u16, format_4), (10, format_12)]
+    } else {
+        vec![(10, format_12)]
+    };
+
+    let mut cmap = Vec::new();
+    cmap.extend(0_u16.to_be_bytes());
+    cmap.extend((subtables.len() as u16).to_be_bytes());
+    let mut offset = 4 + 8 * subtables.len() as u32;
+    for (encoding, subtable) in &subtables {
+        // Windows.
+        cmap.extend(3_u16.to_be_bytes());
+        cmap.extend(encoding.to_be_bytes());
+        cmap.extend(offset.to_be_bytes());
+        offset += subtable.len() as u32;
+    }
+    for (_, subtable) in subtables {
+        cmap.extend(subtable);
+    }
+
+    cmap
+}
+
+/// A font file made of `tables`, with the checksums filled in.
+fn sfnt(version: [u8; 4], mut tables: Vec<([u8; 4], Vec<u8>)>) -> Vec<u8> {
+    tables.sort_by_key(|(tag, _)| *tag);
+
+    let table_count = tables.len() as u16;
+    let search_range = 16 * (1 << (15 - table_count.leading_zeros())) as u16;
+
+    let mut font = version.to_vec();
+    for value in [
+        table_count,
+        search_range,
+        search_range.trailing_zeros() as u16 - 4,
+        16 * table_count - search_range,
+    ] {
+        font.extend(value.to_be_bytes());
+    }
+
+    let mut offset = 12 + 16 * tables.len() as u32;
+    for (tag, data) in &tables {
+        font.extend(tag);
+        font.extend(checksum(data).to_be_bytes());
+        font.extend(offset.to_be_bytes());
+        font.extend((data.len() as u32).to_be_bytes());
+        offset += data.len().next_multiple_of(4) as u32;
+    }
+
+    let mut head_offset = None;
+    for (tag, data) in &tables {
+        if b"head" == tag {
+            head_offset = Some(font.len());
+        }
+        font.extend(data);
+        pad(&mut font);
+    }
+
+    if let Some(head_offset) = head_offset {
+        let adjustment = 0xB1B0_AFBA_u32.wrapping_sub(checksum(&font));
+        font[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+
+    font
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0, |sum, chunk| {
+        let mut bytes = [0; 4];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(bytes))
+    })
+}
+
+/// Pads `data` with zeros to a multiple of four bytes, which tables and,
+/// by convention, glyphs are aligned to.
+fn pad(data: &mut Vec<u8>) {
+    data.resize(data.len().next_multiple_of(4), 0);
+}
+
+fn check_length(table: &[u8], length: usize, name: &str) -> Result<()> {
+    if table.len() < length {
+        return Err(invalid_data(format!("truncated `{name}` table")));
+    }
+    Ok(())
+}
+
+fn invalid_data(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
+    Error::new(ErrorKind::InvalidData, error)
+}
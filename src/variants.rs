@@ -0,0 +1,186 @@
+// Styled forms of ASCII letters and digits are found in the Enclosed
+// Alphanumerics (& Supplement), Halfwidth and Fullwidth Forms and
+// Mathematical Alphanumeric Symbols blocks, at fixed offsets.
+
+// The first codepoint of each letter variant: (capital A, small a).
+const LETTER_VARIANTS: [(Option<u32>, Option<u32>); 20] = [
+    // Fullwidth
+    (Some(0xFF21), Some(0xFF41)),
+    // Circled
+    (Some(0x24B6), Some(0x24D0)),
+    // Parenthesized
+    (Some(0x1F110), Some(0x249C)),
+    // Squared
+    (Some(0x1F130), None),
+    // Negative circled
+    (Some(0x1F150), None),
+    // Negative squared
+    (Some(0x1F170), None),
+    // Regional indicator
+    (Some(0x1F1E6), None),
+    // Mathematical bold
+    (Some(0x1D400), Some(0x1D41A)),
+    // Mathematical italic
+    (Some(0x1D434), Some(0x1D44E)),
+    // Mathematical bold italic
+    (Some(0x1D468), Some(0x1D482)),
+    // Mathematical script
+    (Some(0x1D49C), Some(0x1D4B6)),
+    // Mathematical bold script
+    (Some(0x1D4D0), Some(0x1D4EA)),
+    // Mathematical fraktur
+    (Some(0x1D504), Some(0x1D51E)),
+    // Mathematical double-struck
+    (Some(0x1D538), Some(0x1D552)),
+    // Mathematical bold fraktur
+    (Some(0x1D56C), Some(0x1D586)),
+    // Mathematical sans-serif
+    (Some(0x1D5A0), Some(0x1D5BA)),
+    // Mathematical sans-serif bold
+    (Some(0x1D5D4), Some(0x1D5EE)),
+    // Mathematical sans-serif italic
+    (Some(0x1D608), Some(0x1D622)),
+    // Mathematical sans-serif bold italic
+    (Some(0x1D63C), Some(0x1D656)),
+    // Mathematical monospace
+    (Some(0x1D670), Some(0x1D68A)),
+];
+
+// The codepoint of each digit variant, '0' through '9'.
+const DIGIT_VARIANTS: [[u32; 10]; 10] = [
+    // Fullwidth
+    [
+        0xFF10, 0xFF11, 0xFF12, 0xFF13, 0xFF14, 0xFF15, 0xFF16, 0xFF17, 0xFF18, 0xFF19,
+    ],
+    // Superscript
+    [
+        0x2070, 0x00B9, 0x00B2, 0x00B3, 0x2074, 0x2075, 0x2076, 0x2077, 0x2078, 0x2079,
+    ],
+    // Subscript
+    [
+        0x2080, 0x2081, 0x2082, 0x2083, 0x2084, 0x2085, 0x2086, 0x2087, 0x2088, 0x2089,
+    ],
+    // Circled
+    [
+        0x24EA, 0x2460, 0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468,
+    ],
+    // Negative circled
+    [
+        0x24FF, 0x2776, 0x2777, 0x2778, 0x2779, 0x277A, 0x277B, 0x277C, 0x277D, 0x277E,
+    ],
+    // Mathematical bold
+    [
+        0x1D7CE, 0x1D7CF, 0x1D7D0, 0x1D7D1, 0x1D7D2, 0x1D7D3, 0x1D7D4, 0x1D7D5, 0x1D7D6, 0x1D7D7,
+    ],
+    // Mathematical double-struck
+    [
+        0x1D7D8, 0x1D7D9, 0x1D7DA, 0x1D7DB, 0x1D7DC, 0x1D7DD, 0x1D7DE, 0x1D7DF, 0x1D7E0, 0x1D7E1,
+    ],
+    // Mathematical sans-serif
+    [
+        0x1D7E2, 0x1D7E3, 0x1D7E4, 0x1D7E5, 0x1D7E6, 0x1D7E7, 0x1D7E8, 0x1D7E9, 0x1D7EA, 0x1D7EB,
+    ],
+    // Mathematical sans-serif bold
+    [
+        0x1D7EC, 0x1D7ED, 0x1D7EE, 0x1D7EF, 0x1D7F0, 0x1D7F1, 0x1D7F2, 0x1D7F3, 0x1D7F4, 0x1D7F5,
+    ],
+    // Mathematical monospace
+    [
+        0x1D7F6, 0x1D7F7, 0x1D7F8, 0x1D7F9, 0x1D7FA, 0x1D7FB, 0x1D7FC, 0x1D7FD, 0x1D7FE, 0x1D7FF,
+    ],
+];
+
+/// Returns the styled variants of an ASCII letter or digit, `base` itself
+/// first, or an empty list for any other character.
+pub fn variants(base: char) -> Vec<char> {
+    let offset = |start: u32, first: char| start + base as u32 - first as u32;
+
+    let codepoints: Vec<_> = if base.is_ascii_uppercase() {
+        LETTER_VARIANTS
+            .iter()
+            .filter_map(|(upper, _)| upper.map(|start| offset(start, 'A')))
+            .collect()
+    } else if base.is_ascii_lowercase() {
+        LETTER_VARIANTS
+            .iter()
+            .filter_map(|(_, lower)| lower.map(|start| offset(start, 'a')))
+            .collect()
+    } else if base.is_ascii_digit() {
+        DIGIT_VARIANTS
+            .iter()
+            .map(|digits| digits[base as usize - '0' as usize])
+            .collect()
+    } else {
+        return Vec::new();
+    };
+
+    std::iter::once(base)
+        .chain(
+            codepoints
+                .into_iter()
+                .filter_map(|codepoint| char::from_u32(math_alphanumeric_hole(codepoint))),
+        )
+        .collect()
+}
+
+/// Returns the ASCII letter or digit `chr` is a variant of, if any.
+pub fn base_of(chr: char) -> Option<char> {
+    ('0'..='9')
+        .chain('A'..='Z')
+        .chain('a'..='z')
+        .find(|&base| variants(base).contains(&chr))
+}
+
+/// The small capital of each ASCII letter, `a` through `z`. There is none
+/// for `x`.
+const SMALL_CAPITALS: [char; 26] = [
+    'ᴀ', 'ʙ', 'ᴄ', 'ᴅ', 'ᴇ', 'ꜰ', 'ɢ', 'ʜ', 'ɪ', 'ᴊ', 'ᴋ', 'ʟ', 'ᴍ', 'ɴ', 'ᴏ', 'ᴘ', 'ꞯ', 'ʀ', 'ꜱ',
+    'ᴛ', 'ᴜ', 'ᴠ', 'ᴡ', 'x', 'ʏ', 'ᴢ',
+];
+
+/// Replaces the ASCII letters of `text` with small capitals, e.g. `Glyph`
+/// becomes `ɢʟʏᴘʜ`.
+pub fn small_caps(text: &str) -> String {
+    text.chars()
+        .map(|chr| match chr.to_ascii_lowercase() {
+            lower @ 'a'..='z' => SMALL_CAPITALS[lower as usize - 'a' as usize],
+            _ => chr,
+        })
+        .collect()
+}
+
+/// Letters missing from the Mathematical Alphanumeric Symbols block were
+/// encoded earlier in Letterlike Symbols.
+fn math_alphanumeric_hole(codepoint: u32) -> u32 {
+    match codepoint {
+        // Italic
+        0x1D455 => 0x210E,
+        // Script
+        0x1D49D => 0x212C,
+        0x1D4A0 => 0x2130,
+        0x1D4A1 => 0x2131,
+        0x1D4A3 => 0x210B,
+        0x1D4A4 => 0x2110,
+        0x1D4A7 => 0x2112,
+        0x1D4A8 => 0x2133,
+        0x1D4AD => 0x211B,
+        0x1D4BA => 0x212F,
+        0x1D4BC => 0x210A,
+        0x1D4C4 => 0x2134,
+        // Fraktur
+        0x1D506 => 0x212D,
+        0x1D50B => 0x210C,
+        0x1D50C => 0x2111,
+        0x1D515 => 0x211C,
+        0x1D51D => 0x2128,
+        // Double-struck
+        0x1D53A => 0x2102,
+        0x1D53F => 0x210D,
+        0x1D545 => 0x2115,
+        0x1D547 => 0x2119,
+        0x1D548 => 0x211A,
+        0x1D549 => 0x211D,
+        0x1D551 => 0x2124,
+        _ => codepoint,
+    }
+}